@@ -1,5 +1,4 @@
-//! Demonstrates gliding on the ground plane (currently, only implemented for orbit and slide
-//! operations).
+//! Demonstrates gliding on the ground plane.
 
 #![allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
 
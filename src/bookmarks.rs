@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use trackball::{Frame, Scope};
+
+use super::TrackballCamera;
+
+/// Camera state of [`TrackballCamera`] stored in [`TrackballBookmarks`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackballBookmark {
+	/// Camera frame, see [`TrackballCamera::frame`].
+	pub frame: Frame<f32>,
+	/// Camera scope, see [`TrackballCamera::scope`].
+	pub scope: Scope<f32>,
+	/// Reset frame, see [`TrackballCamera::reset`].
+	pub reset: Frame<f32>,
+}
+
+impl From<&TrackballCamera> for TrackballBookmark {
+	fn from(trackball: &TrackballCamera) -> Self {
+		Self {
+			frame: trackball.frame,
+			scope: trackball.scope,
+			reset: trackball.reset,
+		}
+	}
+}
+
+/// Bookmarks component storing named camera states of [`TrackballCamera`].
+///
+/// Spawned together with a [`TrackballCamera`], its current state is stored by
+/// [`TrackballAction::StoreBookmark`] and recalled with an animated transition by
+/// [`TrackballAction::RecallBookmark`] where slots `1` to `9` are stored under the names `"1"` to
+/// `"9"`. Bookmarks of any other name are stored and recalled programmatically. Recalling restores
+/// [`TrackballCamera::scope`] and [`TrackballCamera::reset`] immediately while flying to the stored
+/// frame, see [`TrackballCamera::fly_to`].
+///
+/// With the `serialize` feature gate, bookmarks round-trip through `serde` formats (e.g., RON or
+/// JSON), so viewpoints can be shared.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_trackball::prelude::*;
+///
+/// let [target, eye, up] = [Vec3::ZERO, Vec3::Z * 10.0, Vec3::Y];
+/// let mut trackball = TrackballCamera::look_at(target, eye, up);
+/// let mut bookmarks = TrackballBookmarks::default();
+/// bookmarks.store("overview", &trackball);
+///
+/// trackball.frame = TrackballCamera::look_at(target, Vec3::X * 5.0, up).frame;
+/// assert!(bookmarks.recall("overview", &mut trackball));
+/// assert!(trackball.is_flying());
+/// assert!(!bookmarks.recall("detail", &mut trackball));
+///
+/// # #[cfg(feature = "serialize")]
+/// # {
+/// let ron = ron::to_string(&bookmarks).unwrap();
+/// assert_eq!(ron::from_str::<TrackballBookmarks>(&ron).unwrap(), bookmarks);
+/// # }
+/// ```
+///
+/// [`TrackballAction::StoreBookmark`]: crate::TrackballAction::StoreBookmark
+/// [`TrackballAction::RecallBookmark`]: crate::TrackballAction::RecallBookmark
+#[derive(Component, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackballBookmarks {
+	/// Camera states by name.
+	pub bookmarks: BTreeMap<String, TrackballBookmark>,
+	/// Duration of flying to recalled frame in milliseconds. Default is `500.0`.
+	pub duration: f32,
+}
+
+impl Default for TrackballBookmarks {
+	fn default() -> Self {
+		Self {
+			bookmarks: BTreeMap::new(),
+			duration: 500.0,
+		}
+	}
+}
+
+impl TrackballBookmarks {
+	/// Defines duration of flying to recalled frame, see [`Self::duration`].
+	#[must_use]
+	pub const fn with_duration(mut self, duration: f32) -> Self {
+		self.duration = duration;
+		self
+	}
+	/// Stores current state of `trackball` under `name` replacing any previous bookmark.
+	pub fn store(&mut self, name: impl Into<String>, trackball: &TrackballCamera) {
+		self.bookmarks.insert(name.into(), trackball.into());
+	}
+	/// Recalls bookmark of `name` into `trackball` and returns whether it exists.
+	pub fn recall(&self, name: &str, trackball: &mut TrackballCamera) -> bool {
+		let Some(bookmark) = self.bookmarks.get(name) else {
+			return false;
+		};
+		trackball.scope = bookmark.scope;
+		trackball.reset = bookmark.reset;
+		trackball.fly_to(bookmark.frame, self.duration, EaseFunction::CubicInOut);
+		true
+	}
+	/// Bookmark of `name`.
+	#[must_use]
+	pub fn get(&self, name: &str) -> Option<&TrackballBookmark> {
+		self.bookmarks.get(name)
+	}
+	/// Removes and returns bookmark of `name`.
+	pub fn remove(&mut self, name: &str) -> Option<TrackballBookmark> {
+		self.bookmarks.remove(name)
+	}
+}
@@ -1,7 +1,19 @@
 use std::collections::HashMap;
 
-use bevy::prelude::*;
-use trackball::{Clamp, Delta, Fixed, Frame, Scope, approx::AbsDiffEq, nalgebra::Point2};
+use bevy::{
+	math::bounding::{Aabb3d, BoundingSphere, BoundingVolume},
+	prelude::*,
+};
+use trackball::{
+	Clamp, Delta, Fixed, Frame, Scope,
+	approx::AbsDiffEq,
+	nalgebra::{Point2, Point3, UnitQuaternion, Vector3},
+};
+
+use super::{
+	StandardView, TrackballController, TrackballFlightMessage, TrackballMessage,
+	clamps::{Elastic, Limit, Limits},
+};
 
 /// Trackball camera component mainly defined by [`Frame`] and [`Scope`].
 #[derive(Component, Debug)]
@@ -25,19 +37,27 @@ pub struct TrackballCamera {
 	///   * clip planes either measured from eye (default) or target (object inspection mode)
 	pub scope: Scope<f32>,
 	old_scope: Scope<f32>,
-	old_max: Point2<f32>,
+	pub(crate) old_max: Point2<f32>,
 	/// Blend half-life from 0 (fast) to 1000 (slow) milliseconds. Default is `40.0`.
 	///
 	/// It is the time passed until halfway of fps-agnostic exponential ease-out.
 	pub blend: f32,
-	/// Camera frame to reset to when [`TrackballInput::reset_key`] is pressed.
+	/// Camera frame to reset to when [`TrackballAction::Reset`] is active.
 	///
-	/// [`TrackballInput::reset_key`]: crate::TrackballInput::reset_key
+	/// [`TrackballAction::Reset`]: crate::TrackballAction::Reset
 	pub reset: Frame<f32>,
 	/// User boundary conditions clamping camera [`Frame`].
 	///
 	/// Allows to limit target/eye position or minimal/maximal target/eye distance or up rotation.
 	pub clamp: Option<Box<dyn Clamp<f32>>>,
+	/// Configured limits of [`Self::clamp`] drawn by `TrackballGizmoPlugin`.
+	///
+	/// Defined by [`Self::with_limits`] and cleared by [`Self::with_clamp`].
+	pub limits: Vec<Limit>,
+	/// Elastic boundary mode of [`Self::clamp`] overshooting and springing back.
+	///
+	/// Hard boundary mode if `None` (default).
+	pub elastic: Option<Elastic>,
 	pub(crate) delta: Option<Delta<f32>>,
 	/// Additional [`TrackballController`] entities to which this camera is sensitive.
 	///
@@ -49,6 +69,17 @@ pub struct TrackballCamera {
 	/// [`TrackballController`]: crate::TrackballController
 	/// [`TrackballMessage`]: crate::TrackballMessage
 	pub group: HashMap<Entity, bool>,
+	pub(crate) flight: Option<Flight>,
+	pub(crate) clamped: bool,
+	followed: bool,
+	/// World up direction of [`StandardView`]. Default is [`Dir3::Y`].
+	pub view_up: Dir3,
+	/// World forward direction of [`StandardView`]. Default is [`Dir3::NEG_Z`].
+	///
+	/// Only its component orthogonal to [`Self::view_up`] is considered.
+	pub view_forward: Dir3,
+	/// Duration of animating to [`StandardView`] in milliseconds. Default is `300.0`.
+	pub view_duration: f32,
 }
 
 impl TrackballCamera {
@@ -65,8 +96,16 @@ impl TrackballCamera {
 			blend: 40.0,
 			reset: frame,
 			clamp: None,
+			limits: Vec::new(),
+			elastic: None,
 			delta: None,
 			group: HashMap::default(),
+			flight: None,
+			clamped: false,
+			followed: false,
+			view_up: Dir3::Y,
+			view_forward: Dir3::NEG_Z,
+			view_duration: 300.0,
 		}
 	}
 	/// Defines scope, see [`Self::scope`].
@@ -94,6 +133,29 @@ impl TrackballCamera {
 	#[allow(clippy::type_complexity)]
 	pub fn with_clamp(mut self, clamp: impl Clamp<f32>) -> Self {
 		self.clamp = Some(Box::new(clamp));
+		self.limits.clear();
+		self
+	}
+	/// Defines user boundary conditions with their configured limits, see [`Self::limits`].
+	#[must_use]
+	pub fn with_limits(self, clamp: impl Clamp<f32> + Limits) -> Self {
+		let limits = clamp.limits();
+		Self {
+			limits,
+			..self.with_clamp(clamp)
+		}
+	}
+	/// Defines elastic boundary mode, see [`Self::elastic`].
+	#[must_use]
+	pub const fn with_elastic(mut self, elastic: Elastic) -> Self {
+		self.elastic = Some(elastic);
+		self
+	}
+	/// Defines world basis of [`StandardView`], see [`Self::view_up`] and [`Self::view_forward`].
+	#[must_use]
+	pub const fn with_view_basis(mut self, up: Dir3, forward: Dir3) -> Self {
+		self.view_up = up;
+		self.view_forward = forward;
 		self
 	}
 	/// Adds additional controller to which this camera is sensitive, see [`Self::group`].
@@ -102,30 +164,290 @@ impl TrackballCamera {
 		self.group.insert(id, rigid);
 		self
 	}
+	/// Flies to `frame` within `duration` in milliseconds using `easing` function.
+	///
+	/// Interpolates target position linearly, eye rotation spherically, and distance exponentially
+	/// (i.e., scaling at a constant rate) starting from the currently rendered frame. Replaces any
+	/// flight in progress and bypasses [`Self::blend`] as well as [`Self::clamp`].
+	///
+	/// The flight is canceled as soon as this camera receives any [`TrackballMessage`] originating
+	/// from user input, see [`TrackballMessage::user`], leaving the camera where it is. In either
+	/// case, a [`TrackballFlightMessage`] is sent when the flight has ended.
+	///
+	/// [`TrackballMessage`]: crate::TrackballMessage
+	/// [`TrackballMessage::user`]: crate::TrackballMessage::user
+	pub fn fly_to(&mut self, frame: Frame<f32>, duration: f32, easing: EaseFunction) {
+		let from = if self.old_frame == Frame::default() {
+			self.frame
+		} else {
+			self.old_frame
+		};
+		self.flight = Some(Flight {
+			from,
+			to: frame,
+			duration: duration * 1e-3,
+			elapsed: 0.0,
+			easing,
+		});
+	}
+	/// Frame fitting axis-aligned bounding box into viewport.
+	///
+	/// Slides the target to the center of `aabb` and scales the distance such that `aabb` fits
+	/// into the viewport preserving the current eye rotation. The fit respects the field of view
+	/// mode of [`Self::scope`], the projection mode, and the aspect ratio of the viewport. The
+	/// relative `margin` enlarges the fitted size (e.g., `0.1` for 10 % on each side).
+	#[must_use]
+	pub fn fit_aabb(&self, aabb: &Aabb3d, margin: f32) -> Frame<f32> {
+		let center = Vec3::from(aabb.center());
+		let half_size = Vec3::from(aabb.half_size());
+		let (tan, ortho) = self.fit_tangents();
+		let rot = self.frame.view().rotation.inverse();
+		let mut zat = 0.0f32;
+		for corner in 0..8 {
+			let vec = half_size
+				* Vec3::new(
+					if corner & 1 == 0 { -1.0 } else { 1.0 },
+					if corner & 2 == 0 { -1.0 } else { 1.0 },
+					if corner & 4 == 0 { -1.0 } else { 1.0 },
+				);
+			let vec = rot * Vector3::from(vec);
+			let fit = (vec.x.abs() / tan.x).max(vec.y.abs() / tan.y) * (1.0 + margin);
+			zat = zat.max(if ortho { fit } else { fit + vec.z });
+		}
+		self.fit_frame(center, zat)
+	}
+	/// Frame fitting bounding sphere into viewport.
+	///
+	/// Like [`Self::fit_aabb`] but independent of the current eye rotation.
+	#[must_use]
+	pub fn fit_sphere(&self, sphere: &BoundingSphere, margin: f32) -> Frame<f32> {
+		let (tan, ortho) = self.fit_tangents();
+		let tan = tan.x.min(tan.y);
+		let radius = sphere.radius() * (1.0 + margin);
+		let zat = if ortho {
+			radius / tan
+		} else {
+			radius * tan.recip().hypot(1.0)
+		};
+		self.fit_frame(sphere.center().into(), zat)
+	}
+	/// Frame with target moved to `pos` preserving eye rotation.
+	///
+	/// Adjusts the distance to the depth of `pos` if `distance` is `true`, so the eye moves
+	/// laterally only. Otherwise, the distance is preserved.
+	#[must_use]
+	pub fn frame_target(&self, pos: Vec3, distance: bool) -> Frame<f32> {
+		let mut frame = self.frame;
+		let pos = Point3::from(pos);
+		let depth = (frame.eye() - pos).dot(&frame.roll_axis());
+		if distance && depth > 0.0 {
+			frame.set_distance(depth);
+		}
+		frame.slide(&(pos - frame.target()));
+		frame
+	}
+	/// Frame of standard `view` preserving target and distance.
+	///
+	/// Returns the current frame if [`Self::view_up`] and [`Self::view_forward`] are parallel.
+	///
+	/// ```
+	/// use bevy::prelude::*;
+	/// use bevy_trackball::prelude::*;
+	///
+	/// let camera = TrackballCamera::look_at(Vec3::ONE, Vec3::new(4.0, 5.0, 1.0), Vec3::Y);
+	/// let frame = camera.frame_view(StandardView::Right);
+	/// assert_abs_diff_eq!(frame.target(), &Point3::new(1.0, 1.0, 1.0));
+	/// assert_abs_diff_eq!(frame.eye(), Point3::new(6.0, 1.0, 1.0), epsilon = 1e-6);
+	/// assert_abs_diff_eq!(frame.yaw_axis(), Vector3::y_axis(), epsilon = 1e-6);
+	///
+	/// let frame = camera.frame_view(StandardView::Top);
+	/// assert_abs_diff_eq!(frame.eye(), Point3::new(1.0, 6.0, 1.0), epsilon = 1e-6);
+	/// assert_abs_diff_eq!(frame.yaw_axis(), -Vector3::z_axis(), epsilon = 1e-6);
+	/// ```
+	#[must_use]
+	pub fn frame_view(&self, view: StandardView) -> Frame<f32> {
+		let Some((dir, up)) = view.direction_and_up(self.view_up, self.view_forward) else {
+			return self.frame;
+		};
+		let target = *self.frame.target();
+		let eye = target + Vector3::from(*dir) * self.frame.distance();
+		Frame::look_at(target, &eye, &Vector3::from(*up))
+	}
+	/// Tangents of half field of view and whether projection mode is orthographic.
+	fn fit_tangents(&self) -> (Point2<f32>, bool) {
+		let max = if self.old_max.x > 0.0 && self.old_max.y > 0.0 {
+			self.old_max
+		} else {
+			Point2::new(1.0, 1.0)
+		};
+		(
+			self.scope.fov().max_and_upp(1.0, &max).0,
+			self.scope.ortho(),
+		)
+	}
+	/// Current frame with target slid to `center` and distance set to `zat`.
+	fn fit_frame(&self, center: Vec3, zat: f32) -> Frame<f32> {
+		let mut frame = self.frame;
+		frame.slide(&(Point3::from(center) - frame.target()));
+		if zat > 0.0 && zat.is_finite() {
+			frame.set_distance(zat);
+		}
+		frame
+	}
+	/// Whether the last delta of this camera has been clamped.
+	///
+	/// See [`TrackballClampMessage`].
+	///
+	/// [`TrackballClampMessage`]: crate::TrackballClampMessage
+	#[must_use]
+	pub const fn is_clamped(&self) -> bool {
+		self.clamped
+	}
+	/// Whether a flight started by [`Self::fly_to`] is in progress.
+	#[must_use]
+	pub const fn is_flying(&self) -> bool {
+		self.flight.is_some()
+	}
+	/// Moves current, rendered, and reset frame as well as flight by slide `vec` followed by orbit
+	/// `rot` around `pos` in world space, see [`TrackballFollow`].
+	///
+	/// [`TrackballFollow`]: crate::TrackballFollow
+	pub(crate) fn follow(
+		&mut self,
+		vec: &Vector3<f32>,
+		rot: &UnitQuaternion<f32>,
+		pos: &Point3<f32>,
+	) {
+		let follow = |frame: &mut Frame<f32>| {
+			frame.slide(vec);
+			frame.orbit_around(rot, pos);
+		};
+		follow(&mut self.frame);
+		if self.old_frame != Frame::default() {
+			follow(&mut self.old_frame);
+		}
+		follow(&mut self.reset);
+		if let Some(flight) = &mut self.flight {
+			follow(&mut flight.from);
+			follow(&mut flight.to);
+		}
+		self.followed = true;
+	}
+}
+
+/// Flight of [`TrackballCamera`] started by [`TrackballCamera::fly_to`].
+#[derive(Debug, Clone, Copy)]
+pub struct Flight {
+	from: Frame<f32>,
+	to: Frame<f32>,
+	duration: f32,
+	elapsed: f32,
+	easing: EaseFunction,
+}
+
+impl Flight {
+	/// Advances flight by `t` seconds returning interpolated frame and whether it has arrived.
+	fn advance(&mut self, t: f32) -> (Frame<f32>, bool) {
+		self.elapsed += t;
+		let t = if self.duration > 0.0 {
+			(self.elapsed / self.duration).min(1.0)
+		} else {
+			1.0
+		};
+		if t < 1.0 {
+			(self.interpolate(self.easing.sample_clamped(t)), false)
+		} else {
+			(self.to, true)
+		}
+	}
+	/// Interpolates between initial and final frame.
+	fn interpolate(&self, t: f32) -> Frame<f32> {
+		let pos = self.from.target().lerp(self.to.target(), t);
+		let old_rot = self.from.view().rotation;
+		let new_rot = self.to.view().rotation;
+		let rot = old_rot
+			.try_slerp(&new_rot, t, f32::EPSILON)
+			.unwrap_or_else(|| old_rot.nlerp(&new_rot, t));
+		let (old_zat, new_zat) = (self.from.distance(), self.to.distance());
+		let zat = if old_zat > 0.0 && new_zat > 0.0 {
+			old_zat * (new_zat / old_zat).powf(t)
+		} else {
+			(new_zat - old_zat).mul_add(t, old_zat)
+		};
+		let mut frame = Frame::default();
+		frame.local_orbit(&rot);
+		frame.set_distance(zat);
+		frame.slide(&pos.coords);
+		frame
+	}
 }
 
 #[allow(clippy::needless_pass_by_value)]
 pub fn trackball_camera(
 	time: Res<Time>,
 	mut cameras: Query<(
+		Entity,
 		&Camera,
 		&mut TrackballCamera,
 		&mut Transform,
 		&mut Projection,
 	)>,
+	controllers: Query<&TrackballController>,
+	mut trackball_events: MessageWriter<TrackballMessage>,
+	mut flight_events: MessageWriter<TrackballFlightMessage>,
 ) {
-	for (camera, mut trackball, mut transform, mut projection) in &mut cameras {
+	for (entity, camera, mut trackball, mut transform, mut projection) in &mut cameras {
 		let Some(max) = camera.logical_viewport_size().map(Point2::from) else {
 			continue;
 		};
+		let moved = || {
+			std::iter::once(&entity)
+				.chain(trackball.group.keys())
+				.filter_map(|&group| controllers.get(group).ok())
+				.any(|controller| controller.is_held() || controller.is_coasting())
+		};
+		if let (Some(clamp), Some(elastic)) = (&trackball.clamp, &trackball.elastic)
+			&& trackball.flight.is_none()
+			&& !moved()
+			&& let Some(deltas) =
+				elastic.spring_back(clamp.as_ref(), &trackball.frame, time.delta_secs())
+		{
+			for delta in deltas.into_iter().filter(|&delta| delta != Delta::Frame) {
+				trackball_events.write(TrackballMessage {
+					group: entity,
+					delta,
+					setup: None,
+					user: false,
+				});
+			}
+		}
+		let flying = if let Some(flight) = &mut trackball.flight {
+			let (frame, arrived) = flight.advance(time.delta_secs());
+			trackball.frame = frame;
+			if arrived {
+				trackball.flight = None;
+				flight_events.write(TrackballFlightMessage {
+					camera: entity,
+					arrived,
+				});
+			}
+			true
+		} else {
+			false
+		};
 		#[allow(clippy::float_cmp)]
 		let new_zat = trackball.frame.distance() != trackball.old_frame.distance();
-		if trackball.frame != trackball.old_frame {
+		let followed = std::mem::take(&mut trackball.followed);
+		if trackball.frame != trackball.old_frame || followed {
 			if trackball.old_frame == Frame::default() {
 				trackball.old_frame = trackball.frame;
 			}
-			let blend = (trackball.blend * 1e-3).clamp(0.0, 1.0);
-			let blend = 1.0 - 0.5f32.powf(time.delta_secs() / blend);
+			let blend = if flying {
+				1.0
+			} else {
+				let blend = (trackball.blend * 1e-3).clamp(0.0, 1.0);
+				1.0 - 0.5f32.powf(time.delta_secs() / blend)
+			};
 			trackball.old_frame = trackball
 				.old_frame
 				.abs_diff_ne(&trackball.frame, f32::EPSILON.sqrt())
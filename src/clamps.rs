@@ -0,0 +1,1298 @@
+//! Ready-made boundary conditions implementing [`Clamp`].
+//!
+//! Each clamp restricts a single aspect of [`Frame`] and can be combined with others via [`All`]
+//! before being assigned to [`TrackballCamera::clamp`]. Exceeded boundary conditions are
+//! communicated as boundary planes, so the camera glides along them instead of stopping.
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_trackball::{
+//! 	clamps::{All, Distance, HalfSpace, Pitch},
+//! 	prelude::*,
+//! };
+//!
+//! let clamp = All::default()
+//! 	.with(Distance::new(2.0, 50.0))
+//! 	.with(Pitch::new(Vector3::y_axis(), -0.1, 1.4))
+//! 	.with(HalfSpace::new(Plane::new(Vector3::y_axis(), 0.3)));
+//! let trackball = TrackballCamera::look_at(Vec3::Y, Vec3::new(0.0, 7.0, 14.0), Vec3::Y)
+//! 	.with_clamp(clamp);
+//! ```
+//!
+//! Besides analytic boundaries like [`Terrain`], the eye can be kept outside of arbitrary meshes
+//! marked as [`Collider`] via [`Colliders::clamp`] (e.g., walls of interior architecture scenes)
+//! once [`TrackballCollidersPlugin`] is added.
+//!
+//! [`TrackballCamera::clamp`]: crate::TrackballCamera::clamp
+
+mod collision;
+
+pub use collision::{Collider, Colliders, Collision, TrackballCollidersPlugin};
+
+use std::{fmt, sync::Arc};
+
+use bevy::prelude::*;
+use trackball::{
+	Bound, Clamp, Delta, Frame, Plane, Scope,
+	approx::AbsDiffEq,
+	nalgebra::{Isometry3, Point3, Unit, Vector3},
+};
+
+/// Default hysteresis allowing clamped deltas to more likely pass revalidation.
+fn hysteresis() -> f32 {
+	f32::default_epsilon().sqrt()
+}
+
+/// Configured limit of a clamp in world space, see [`Limits`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+	/// Target or eye position within box between `min` and `max` components transformed by
+	/// `transform`.
+	Box {
+		/// Isometry in world space of box.
+		transform: Isometry3<f32>,
+		/// Minimum components of box.
+		min: Point3<f32>,
+		/// Maximum components of box.
+		max: Point3<f32>,
+	},
+	/// Eye position within sphere of `radius` around `center`.
+	Sphere {
+		/// Center of sphere in world space.
+		center: Point3<f32>,
+		/// Radius of sphere.
+		radius: f32,
+	},
+	/// Target or eye position in front of plane.
+	Plane(Plane<f32>),
+	/// Distance of eye from target position between `min` and `max`.
+	Distance {
+		/// Minimum distance.
+		min: f32,
+		/// Maximum distance.
+		max: f32,
+	},
+	/// Pitch relative to `up` axis between `min` and `max` angles in radians around target.
+	Pitch {
+		/// Up axis in world space.
+		up: Unit<Vector3<f32>>,
+		/// Minimum pitch angle in radians.
+		min: f32,
+		/// Maximum pitch angle in radians.
+		max: f32,
+	},
+}
+
+/// Configured limits of a clamp independent of whether they are active.
+///
+/// Whereas [`Clamp`] only reports exceeded boundary planes, this typed hook describes the whole
+/// boundary (e.g., to be drawn by `TrackballGizmoPlugin`). Implemented for the clamps of this
+/// module with closed-form boundaries and for [`Bound`] except its up limits. Unbounded limits
+/// (i.e., [`f32::MAX`] or [`f32::MIN`]) are omitted.
+///
+/// ```
+/// use bevy_trackball::{
+/// 	clamps::{All, Distance, HalfSpace, Limit, Limits, Pitch},
+/// 	prelude::*,
+/// };
+///
+/// let clamp = All::default()
+/// 	.with_limits(Distance::new(2.0, 50.0))
+/// 	.with_limits(HalfSpace::new(Plane::new(Vector3::y_axis(), 0.3)))
+/// 	.with(Pitch::new(Vector3::y_axis(), -0.1, 1.4));
+/// assert_eq!(
+/// 	clamp.limits(),
+/// 	[
+/// 		Limit::Distance {
+/// 			min: 2.0,
+/// 			max: 50.0
+/// 		},
+/// 		Limit::Plane(Plane::new(Vector3::y_axis(), 0.3)),
+/// 	]
+/// );
+/// ```
+pub trait Limits {
+	/// Configured limits in world space.
+	#[must_use]
+	fn limits(&self) -> Vec<Limit>;
+}
+
+/// Whether `value` is bounded, i.e., neither [`f32::MAX`] nor [`f32::MIN`].
+fn bounded(value: f32) -> bool {
+	value.abs() < f32::MAX
+}
+
+impl Limits for Bound<f32> {
+	fn limits(&self) -> Vec<Limit> {
+		let mut limits = Vec::new();
+		for (min, max) in [
+			(self.min_target, self.max_target),
+			(self.min_eye, self.max_eye),
+		] {
+			if min.iter().chain(max.iter()).all(|&value| bounded(value)) {
+				limits.push(Limit::Box {
+					transform: self.transform,
+					min,
+					max,
+				});
+				continue;
+			}
+			let axes = [Vector3::x_axis(), Vector3::y_axis(), Vector3::z_axis()];
+			for (index, axis) in axes.into_iter().enumerate() {
+				let normal = self.transform.rotation * axis;
+				for value in [min[index], max[index]]
+					.into_iter()
+					.filter(|&value| bounded(value))
+				{
+					let point = self.transform * Point3::from(axis.into_inner() * value);
+					limits.push(Limit::Plane(Plane::with_point(normal, &point)));
+				}
+			}
+		}
+		if self.min_distance > 0.0 || bounded(self.max_distance) {
+			limits.push(Limit::Distance {
+				min: self.min_distance,
+				max: self.max_distance,
+			});
+		}
+		limits
+	}
+}
+
+/// Minimum and maximum distance of eye from target.
+///
+/// ```
+/// use bevy_trackball::{
+/// 	clamps::{self, Distance},
+/// 	prelude::*,
+/// };
+///
+/// let clamp = Distance::new(2.0, 50.0);
+/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 4.0), &Vector3::y());
+/// let scope = Scope::default();
+///
+/// // Within bounds.
+/// assert!(clamp.eye(&frame).is_none());
+///
+/// // Exceeding minimum distance by zooming in.
+/// let delta = Delta::Scale {
+/// 	rat: 0.25,
+/// 	pos: Point3::origin(),
+/// };
+/// assert!(clamp.eye(&delta.transform(&frame)).is_some());
+/// let (delta, _loops, _bisections) = clamps::compute(&clamp, &frame, &scope, &delta).unwrap();
+///
+/// // Stops at minimum distance.
+/// assert_abs_diff_eq!(delta.transform(&frame).distance(), 2.0, epsilon = 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distance {
+	/// Minimum distance of eye from target.
+	pub min: f32,
+	/// Maximum distance of eye from target.
+	pub max: f32,
+	/// Epsilon allowing clamped deltas to more likely pass revalidation.
+	pub hysteresis: f32,
+}
+
+impl Distance {
+	/// Distance of eye from target between `min` and `max`.
+	#[must_use]
+	pub fn new(min: f32, max: f32) -> Self {
+		Self {
+			min,
+			max,
+			hysteresis: hysteresis(),
+		}
+	}
+}
+
+impl Default for Distance {
+	fn default() -> Self {
+		Self::new(0.0, f32::MAX)
+	}
+}
+
+impl Clamp<f32> for Distance {
+	fn loops(&self) -> usize {
+		10
+	}
+	fn target(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+	fn eye(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		let distance = frame.distance();
+		let bound = if self.min - distance > self.hysteresis {
+			self.min
+		} else if distance - self.max > self.hysteresis {
+			self.max
+		} else {
+			return None;
+		};
+		let axis = frame.roll_axis();
+		Some(Plane::with_point(
+			axis,
+			&(frame.target() + axis.into_inner() * bound),
+		))
+	}
+	fn up(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+}
+
+impl Limits for Distance {
+	fn limits(&self) -> Vec<Limit> {
+		vec![Limit::Distance {
+			min: self.min,
+			max: self.max,
+		}]
+	}
+}
+
+/// Minimum and maximum pitch (i.e., elevation of eye above target) relative to an up axis.
+///
+/// Pitch angles are in radians from `-FRAC_PI_2` (eye below target) to `FRAC_PI_2` (eye above
+/// target) where zero means eye and target are level.
+///
+/// ```
+/// use bevy_trackball::{clamps::Pitch, prelude::*};
+/// use std::f32::consts::FRAC_PI_6;
+///
+/// let clamp = Pitch::new(Vector3::y_axis(), -FRAC_PI_6, FRAC_PI_6);
+/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 10.0), &Vector3::y());
+/// let scope = Scope::default();
+///
+/// // Orbiting diagonally up and left by 60 degrees.
+/// let axis = Unit::new_normalize(Vector3::new(-1.0, -1.0, 0.0));
+/// let delta = Delta::Orbit {
+/// 	rot: UnitQuaternion::from_axis_angle(&axis, 60f32.to_radians()),
+/// 	pos: Point3::origin(),
+/// };
+/// let (delta, _loops) = clamp.compute(&frame, &scope, &delta).unwrap();
+/// let eye = delta.transform(&frame).eye();
+///
+/// // Glides along maximum pitch instead of stopping.
+/// assert_abs_diff_eq!(eye.y, 5.0, epsilon = 1e-3);
+/// assert!(eye.x < -1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pitch {
+	/// Up axis in world space.
+	pub up: Unit<Vector3<f32>>,
+	/// Minimum pitch angle in radians.
+	pub min: f32,
+	/// Maximum pitch angle in radians.
+	pub max: f32,
+	/// Epsilon allowing clamped deltas to more likely pass revalidation.
+	pub hysteresis: f32,
+}
+
+impl Pitch {
+	/// Pitch relative to `up` axis between `min` and `max` angles in radians.
+	#[must_use]
+	pub fn new(up: Unit<Vector3<f32>>, min: f32, max: f32) -> Self {
+		Self {
+			up,
+			min,
+			max,
+			hysteresis: hysteresis(),
+		}
+	}
+}
+
+impl Clamp<f32> for Pitch {
+	fn target(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+	fn eye(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		let sin = frame.roll_axis().dot(&self.up);
+		let (min, max) = (self.min.sin(), self.max.sin());
+		let bound = if min - sin > self.hysteresis {
+			min
+		} else if sin - max > self.hysteresis {
+			max
+		} else {
+			return None;
+		};
+		Some(Plane::with_point(
+			self.up,
+			&(frame.target() + self.up.into_inner() * (frame.distance() * bound)),
+		))
+	}
+	fn up(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+}
+
+impl Limits for Pitch {
+	fn limits(&self) -> Vec<Limit> {
+		vec![Limit::Pitch {
+			up: self.up,
+			min: self.min,
+			max: self.max,
+		}]
+	}
+}
+
+/// Target position confined to an axis-aligned box.
+///
+/// ```
+/// use bevy_trackball::{clamps::TargetBox, prelude::*};
+///
+/// let clamp = TargetBox::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 10.0), &Vector3::y());
+/// let scope = Scope::default();
+///
+/// // Sliding diagonally beyond the right face.
+/// let delta = Delta::Slide {
+/// 	vec: Vector3::new(2.0, 0.5, 0.0),
+/// };
+/// let (delta, _loops) = clamp.compute(&frame, &scope, &delta).unwrap();
+/// let target = *delta.transform(&frame).target();
+///
+/// // Glides along the right face.
+/// assert_abs_diff_eq!(target, Point3::new(1.0, 0.5, 0.0), epsilon = 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TargetBox {
+	/// Minimum components of target position in world space.
+	pub min: Point3<f32>,
+	/// Maximum components of target position in world space.
+	pub max: Point3<f32>,
+	/// Epsilon allowing clamped deltas to more likely pass revalidation.
+	pub hysteresis: f32,
+}
+
+impl TargetBox {
+	/// Target position between `min` and `max` components.
+	#[must_use]
+	pub fn new(min: Point3<f32>, max: Point3<f32>) -> Self {
+		Self {
+			min,
+			max,
+			hysteresis: hysteresis(),
+		}
+	}
+}
+
+impl Clamp<f32> for TargetBox {
+	fn loops(&self) -> usize {
+		10
+	}
+	fn target(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		let target = frame.target();
+		let axes = [Vector3::x_axis(), Vector3::y_axis(), Vector3::z_axis()];
+		for (index, axis) in axes.into_iter().enumerate() {
+			if self.min[index] - target[index] > self.hysteresis {
+				return Some(Plane::new(axis, self.min[index]));
+			}
+			if target[index] - self.max[index] > self.hysteresis {
+				return Some(Plane::new(axis, self.max[index]));
+			}
+		}
+		None
+	}
+	fn eye(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+	fn up(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+}
+
+impl Limits for TargetBox {
+	fn limits(&self) -> Vec<Limit> {
+		vec![Limit::Box {
+			transform: Isometry3::identity(),
+			min: self.min,
+			max: self.max,
+		}]
+	}
+}
+
+/// Eye position confined to a sphere.
+///
+/// ```
+/// use bevy_trackball::{clamps::EyeSphere, prelude::*};
+///
+/// let clamp = EyeSphere::new(Point3::origin(), 10.0);
+/// let frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 10.0), &Vector3::y());
+/// let scope = Scope::default();
+///
+/// // Sliding right beyond the sphere.
+/// let delta = Delta::Slide {
+/// 	vec: Vector3::new(5.0, 0.0, 0.0),
+/// };
+/// let (delta, _loops) = clamp.compute(&frame, &scope, &delta).unwrap();
+/// let eye = delta.transform(&frame).eye();
+///
+/// // Glides onto the sphere.
+/// assert_abs_diff_eq!(eye.coords.norm(), 10.0, epsilon = 1e-3);
+/// assert!(eye.x > 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EyeSphere {
+	/// Center of sphere in world space.
+	pub center: Point3<f32>,
+	/// Radius of sphere.
+	pub radius: f32,
+	/// Epsilon allowing clamped deltas to more likely pass revalidation.
+	pub hysteresis: f32,
+}
+
+impl EyeSphere {
+	/// Eye position within sphere of `radius` around `center`.
+	#[must_use]
+	pub fn new(center: Point3<f32>, radius: f32) -> Self {
+		Self {
+			center,
+			radius,
+			hysteresis: hysteresis(),
+		}
+	}
+}
+
+impl Clamp<f32> for EyeSphere {
+	fn target(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+	fn eye(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		let (normal, norm) = Unit::new_and_get(frame.eye() - self.center);
+		(norm - self.radius > self.hysteresis)
+			.then(|| Plane::with_point(normal, &(self.center + normal.into_inner() * self.radius)))
+	}
+	fn up(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+}
+
+impl Limits for EyeSphere {
+	fn limits(&self) -> Vec<Limit> {
+		vec![Limit::Sphere {
+			center: self.center,
+			radius: self.radius,
+		}]
+	}
+}
+
+/// Eye position confined to the half-space in front of a plane (e.g., above the ground plane).
+///
+/// The allowed half-space is the one the plane normal points to.
+///
+/// ```
+/// use bevy_trackball::{clamps::HalfSpace, prelude::*};
+///
+/// let clamp = HalfSpace::new(Plane::new(Vector3::y_axis(), 0.0));
+/// let frame = Frame::look_at(
+/// 	Point3::new(0.0, 1.0, 0.0),
+/// 	&Point3::new(0.0, 1.0, 10.0),
+/// 	&Vector3::y(),
+/// );
+/// let scope = Scope::default();
+///
+/// // Sliding diagonally down and right below the ground plane.
+/// let delta = Delta::Slide {
+/// 	vec: Vector3::new(2.0, -2.0, 0.0),
+/// };
+/// let (delta, _loops) = clamp.compute(&frame, &scope, &delta).unwrap();
+/// let eye = delta.transform(&frame).eye();
+///
+/// // Glides along the ground plane.
+/// assert_abs_diff_eq!(eye, Point3::new(2.0, 0.0, 10.0), epsilon = 1e-3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HalfSpace {
+	/// Boundary plane in world space.
+	pub plane: Plane<f32>,
+	/// Epsilon allowing clamped deltas to more likely pass revalidation.
+	pub hysteresis: f32,
+}
+
+impl HalfSpace {
+	/// Eye position in front of `plane`.
+	#[must_use]
+	pub fn new(plane: Plane<f32>) -> Self {
+		Self {
+			plane,
+			hysteresis: hysteresis(),
+		}
+	}
+}
+
+impl Clamp<f32> for HalfSpace {
+	fn loops(&self) -> usize {
+		10
+	}
+	fn target(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+	fn eye(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		(self.plane.distance_from(&frame.eye()) > self.hysteresis).then_some(self.plane)
+	}
+	fn up(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+}
+
+impl Limits for HalfSpace {
+	fn limits(&self) -> Vec<Limit> {
+		vec![Limit::Plane(self.plane)]
+	}
+}
+
+/// Eye position kept a minimum height above a terrain heightfield.
+///
+/// The terrain is defined by a height function of the horizontal world coordinates `x` and `z`
+/// where `y` is up. The boundary plane is the tangent plane of the terrain raised by the clearance
+/// below the eye, so the eye glides over hills and valleys. Whether the terrain is currently
+/// constraining the eye is reported by [`constraint`].
+///
+/// ```
+/// use bevy_trackball::{
+/// 	clamps::{self, Terrain},
+/// 	prelude::*,
+/// };
+///
+/// // Slope rising towards negative z.
+/// let clamp = Terrain::new(|_x, z| -0.5 * z, 1.0);
+/// let frame = Frame::look_at(
+/// 	Point3::new(0.0, 4.0, 0.0),
+/// 	&Point3::new(0.0, 4.0, 4.0),
+/// 	&Vector3::y(),
+/// );
+/// let scope = Scope::default();
+///
+/// // Sliding forward into the slope.
+/// let delta = Delta::Slide {
+/// 	vec: Vector3::new(0.0, 0.0, -14.0),
+/// };
+/// let (delta, _loops, _bisections) = clamps::compute(&clamp, &frame, &scope, &delta).unwrap();
+/// let eye = delta.transform(&frame).eye();
+///
+/// // Glides up the slope keeping the clearance.
+/// assert_abs_diff_eq!(eye.y, clamp.height(eye.x, eye.z) + 1.0, epsilon = 1e-3);
+/// assert!(eye.z < -5.0);
+/// ```
+#[derive(Clone)]
+pub struct Terrain {
+	height: Arc<dyn Fn(f32, f32) -> f32 + Send + Sync>,
+	/// Minimum height of eye above terrain.
+	pub clearance: f32,
+	/// Step of finite differences approximating the terrain slope. Default is `0.01`.
+	pub step: f32,
+	/// Epsilon allowing clamped deltas to more likely pass revalidation.
+	pub hysteresis: f32,
+}
+
+impl Terrain {
+	/// Terrain of `height` function with minimum `clearance` of eye above it.
+	#[must_use]
+	pub fn new(height: impl Fn(f32, f32) -> f32 + Send + Sync + 'static, clearance: f32) -> Self {
+		Self {
+			height: Arc::new(height),
+			clearance,
+			step: 0.01,
+			hysteresis: hysteresis(),
+		}
+	}
+	/// Terrain of heightfield `image` with minimum `clearance` of eye above it.
+	///
+	/// The image is stretched over the horizontal `area` in world space where its `x` and `y`
+	/// coordinates map to the world `x` and `z` coordinates. Its red channel from `0.0` to `1.0`
+	/// maps to heights from `min` to `max`. Heights in between pixels are interpolated bilinearly
+	/// and heights outside the `area` are extended from its edges. Returns `None` if the image
+	/// format does not support reading colors.
+	#[must_use]
+	pub fn from_image(
+		image: &Image,
+		area: Rect,
+		min: f32,
+		max: f32,
+		clearance: f32,
+	) -> Option<Self> {
+		let (width, height) = (image.width(), image.height());
+		let mut data = Vec::with_capacity(width as usize * height as usize);
+		for y in 0..height {
+			for x in 0..width {
+				let red = image.get_color_at(x, y).ok()?.to_linear().red;
+				data.push((max - min).mul_add(red, min));
+			}
+		}
+		let (width, height) = (width as usize, height as usize);
+		let size = area.size();
+		let sample = move |x: f32, z: f32| {
+			#[allow(clippy::cast_precision_loss)]
+			let max = Vec2::new((width - 1) as f32, (height - 1) as f32);
+			let pos = ((Vec2::new(x, z) - area.min) / size * max).clamp(Vec2::ZERO, max);
+			let min = pos.floor();
+			let fract = pos - min;
+			#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+			let (x, y) = (min.x as usize, min.y as usize);
+			let (x1, y1) = ((x + 1).min(width - 1), (y + 1).min(height - 1));
+			let row = |y: usize| {
+				let (a, b) = (data[y * width + x], data[y * width + x1]);
+				(b - a).mul_add(fract.x, a)
+			};
+			let (a, b) = (row(y), row(y1));
+			(b - a).mul_add(fract.y, a)
+		};
+		(width > 0 && height > 0).then(|| Self::new(sample, clearance))
+	}
+	/// Height of terrain at horizontal world coordinates `x` and `z`.
+	#[must_use]
+	pub fn height(&self, x: f32, z: f32) -> f32 {
+		(self.height)(x, z)
+	}
+}
+
+impl fmt::Debug for Terrain {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Terrain")
+			.field("clearance", &self.clearance)
+			.field("step", &self.step)
+			.field("hysteresis", &self.hysteresis)
+			.finish_non_exhaustive()
+	}
+}
+
+impl Clamp<f32> for Terrain {
+	fn target(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+	fn eye(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		let eye = frame.eye();
+		let height = self.height(eye.x, eye.z) + self.clearance;
+		if height - eye.y <= self.hysteresis {
+			return None;
+		}
+		let step = self.step;
+		let dx =
+			(self.height(eye.x + step, eye.z) - self.height(eye.x - step, eye.z)) / (2.0 * step);
+		let dz =
+			(self.height(eye.x, eye.z + step) - self.height(eye.x, eye.z - step)) / (2.0 * step);
+		let normal = Unit::new_normalize(Vector3::new(-dx, 1.0, -dz));
+		Some(Plane::with_point(
+			normal,
+			&Point3::new(eye.x, height, eye.z),
+		))
+	}
+	fn up(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+}
+
+/// Combination of clamps which all must be satisfied.
+///
+/// Reports the exceeded boundary plane of the first unsatisfied clamp.
+///
+/// ```
+/// use bevy_trackball::{
+/// 	clamps::{All, HalfSpace, TargetBox},
+/// 	prelude::*,
+/// };
+///
+/// let clamp = All::default()
+/// 	.with(HalfSpace::new(Plane::new(Vector3::y_axis(), 0.0)))
+/// 	.with(TargetBox::new(
+/// 		Point3::new(-1.0, 0.5, -1.0),
+/// 		Point3::new(1.0, 2.0, 1.0),
+/// 	));
+/// let frame = Frame::look_at(
+/// 	Point3::new(0.0, 1.0, 0.0),
+/// 	&Point3::new(0.0, 1.0, 10.0),
+/// 	&Vector3::y(),
+/// );
+/// let scope = Scope::default();
+///
+/// // Sliding diagonally down and right beyond both.
+/// let delta = Delta::Slide {
+/// 	vec: Vector3::new(2.0, -2.0, 0.0),
+/// };
+/// let (delta, _loops) = clamp.compute(&frame, &scope, &delta).unwrap();
+/// let frame = delta.transform(&frame);
+///
+/// // Glides into the corner of both.
+/// assert_abs_diff_eq!(*frame.target(), Point3::new(1.0, 0.5, 0.0), epsilon = 1e-3);
+/// assert_abs_diff_eq!(frame.eye(), Point3::new(1.0, 0.5, 10.0), epsilon = 1e-3);
+/// ```
+#[derive(Debug, Default)]
+pub struct All {
+	/// Clamps to be satisfied.
+	pub clamps: Vec<Box<dyn Clamp<f32>>>,
+	/// Configured limits of clamps added via [`Self::with_limits`].
+	pub limits: Vec<Limit>,
+}
+
+impl All {
+	/// Adds `clamp` to be satisfied as well.
+	#[must_use]
+	pub fn with(mut self, clamp: impl Clamp<f32>) -> Self {
+		self.clamps.push(Box::new(clamp));
+		self
+	}
+	/// Adds `clamp` to be satisfied as well and keeps its configured limits, see [`Limits`].
+	#[must_use]
+	pub fn with_limits(mut self, clamp: impl Clamp<f32> + Limits) -> Self {
+		self.limits.extend(clamp.limits());
+		self.with(clamp)
+	}
+}
+
+impl Clamp<f32> for All {
+	fn loops(&self) -> usize {
+		self.clamps
+			.iter()
+			.map(|clamp| clamp.loops())
+			.max()
+			.unwrap_or(0)
+	}
+	fn target(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		self.clamps.iter().find_map(|clamp| clamp.target(frame))
+	}
+	fn eye(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		self.clamps.iter().find_map(|clamp| clamp.eye(frame))
+	}
+	fn up(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		self.clamps.iter().find_map(|clamp| clamp.up(frame))
+	}
+}
+
+impl Limits for All {
+	fn limits(&self) -> Vec<Limit> {
+		self.limits.clone()
+	}
+}
+
+/// Elastic boundary mode of [`TrackballCamera::clamp`].
+///
+/// Instead of stopping at boundary planes, the camera overshoots them by a damped amount while
+/// being moved and springs back once input and inertia have ended, similar to scroll views on touch
+/// devices. Boundary conditions of [`Clamp::up`] stay hard. Springing back is sent as
+/// [`TrackballMessage`] deltas, so rigid constellations follow and [`TrackballClampMessage`] is
+/// sent like for any other movement, smoothed by [`TrackballCamera::blend`].
+///
+/// ```
+/// use bevy_trackball::{
+/// 	clamps::{Distance, Elastic},
+/// 	prelude::*,
+/// };
+///
+/// let clamp = Distance::new(2.0, 50.0);
+/// let elastic = Elastic::default();
+/// let mut frame = Frame::look_at(Point3::origin(), &Point3::new(0.0, 0.0, 4.0), &Vector3::y());
+/// let scope = Scope::default();
+///
+/// // Overshooting minimum distance by zooming in.
+/// let delta = Delta::Scale {
+/// 	rat: 0.25,
+/// 	pos: Point3::origin(),
+/// };
+/// let delta = elastic.compute(&clamp, &frame, &scope, &delta).unwrap();
+/// frame = delta.transform(&frame);
+/// assert!(frame.distance() < 2.0 && frame.distance() >= 1.0);
+///
+/// // Springing back to minimum distance once input has ended.
+/// while let Some(deltas) = elastic.spring_back(&clamp, &frame, 0.1) {
+/// 	for delta in deltas {
+/// 		frame = delta.transform(&frame);
+/// 	}
+/// }
+/// assert_abs_diff_eq!(frame.distance(), 2.0, epsilon = 1e-3);
+/// ```
+///
+/// [`TrackballCamera::clamp`]: crate::TrackballCamera::clamp
+/// [`TrackballCamera::blend`]: crate::TrackballCamera::blend
+/// [`TrackballMessage`]: crate::TrackballMessage
+/// [`TrackballClampMessage`]: crate::TrackballClampMessage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Elastic {
+	/// Maximum overshoot beyond boundary planes in world units. Default is `1.0`.
+	///
+	/// The closer the camera gets to the maximum overshoot, the more the movement is damped.
+	pub overshoot: f32,
+	/// Spring-back half-life from 0 (stiff) to 1000 (soft) milliseconds. Default is `80.0`.
+	///
+	/// It is the time passed until halfway back to the boundary planes.
+	pub spring: f32,
+}
+
+impl Elastic {
+	/// Elastic boundary mode with maximum `overshoot` and `spring` half-life.
+	#[must_use]
+	pub const fn new(overshoot: f32, spring: f32) -> Self {
+		Self { overshoot, spring }
+	}
+	/// Damped `delta` overshooting boundary planes of `clamp` or `None` if unrestricted.
+	///
+	/// Movements towards the boundary planes are never damped.
+	#[must_use]
+	pub fn compute(
+		&self,
+		clamp: &dyn Clamp<f32>,
+		frame: &Frame<f32>,
+		scope: &Scope<f32>,
+		delta: &Delta<f32>,
+	) -> Option<Delta<f32>> {
+		let new_frame = delta.transform(frame);
+		if clamp.up(&new_frame).is_some() {
+			return compute(clamp, frame, scope, delta).map(|(delta, _loops, _bisections)| delta);
+		}
+		let old_depth = depth(clamp, frame);
+		if depth(clamp, &new_frame) <= old_depth {
+			return None;
+		}
+		let max_depth = self.overshoot.max(old_depth);
+		let delta = delta.lerp_slerp((1.0 - old_depth / self.overshoot).max(0.0));
+		let (t, _bisections) =
+			bisect(|t| depth(clamp, &delta.lerp_slerp(t).transform(frame)) <= max_depth)?;
+		Some(delta.lerp_slerp(t))
+	}
+	/// Deltas springing back towards boundary planes of `clamp` for `t` seconds or `None` if
+	/// within.
+	///
+	/// Springing back the target slides the camera whereas springing back the eye orbits and scales
+	/// around the target, so they are applied in order like any other delta.
+	#[must_use]
+	pub fn spring_back(
+		&self,
+		clamp: &dyn Clamp<f32>,
+		frame: &Frame<f32>,
+		t: f32,
+	) -> Option<[Delta<f32>; 2]> {
+		let spring = (self.spring * 1e-3).clamp(0.0, 1.0);
+		let rat = 1.0 - 0.5f32.powf(t / spring);
+		let rot = frame.view().rotation;
+		if let Some(plane) = clamp.target(frame) {
+			let target = frame.target();
+			let vec = rot.inverse() * ((plane.project_point(target) - target) * rat);
+			return Some([Delta::Slide { vec }, Delta::Frame]);
+		}
+		let plane = clamp.eye(frame)?;
+		let eye = frame.eye();
+		let eye = eye + (plane.project_point(&eye) - eye) * rat;
+		let mut new_frame = *frame;
+		new_frame.set_eye(&eye, &frame.yaw_axis());
+		let pos = Point3::origin();
+		Some([
+			Delta::Orbit {
+				rot: rot.inverse() * new_frame.view().rotation,
+				pos,
+			},
+			Delta::Scale {
+				rat: new_frame.distance() / frame.distance(),
+				pos,
+			},
+		])
+	}
+}
+
+impl Default for Elastic {
+	fn default() -> Self {
+		Self::new(1.0, 80.0)
+	}
+}
+
+/// Maximum depth of target and eye beyond their boundary planes.
+fn depth(clamp: &dyn Clamp<f32>, frame: &Frame<f32>) -> f32 {
+	let target = clamp
+		.target(frame)
+		.map_or(0.0, |plane| plane.distance_from(frame.target()).abs());
+	let eye = clamp
+		.eye(frame)
+		.map_or(0.0, |plane| plane.distance_from(&frame.eye()).abs());
+	target.max(eye)
+}
+
+/// Number of bisections finding the maximum fraction of a delta satisfying boundary conditions.
+const BISECTIONS: usize = 16;
+
+/// Computes clamped [`Delta`] like [`Clamp::compute`] but glides for first-person and scale.
+///
+/// Where [`Clamp::compute`] completely stops [`Delta::First`] and [`Delta::Scale`] whenever a
+/// boundary condition is exceeded, this glides along the boundary planes instead:
+///
+///   * [`Delta::Scale`] keeps its ratio but changes its center such that the exceeded position is
+///     projected onto the boundary plane (e.g., zooming towards the ground slides along it). If
+///     that does not satisfy the boundary conditions (e.g., [`Distance`]), it stops at the
+///     boundary.
+///   * [`Delta::First`] keeps either its pitch or yaw and reduces the other one to its maximum
+///     satisfying the boundary conditions (e.g., looking down at the ground still turns left or
+///     right), whichever moves farther.
+///
+/// Other deltas are passed to [`Clamp::compute`]. Returns `None` if [`Delta`] satisfies all
+/// boundary conditions. Otherwise, returns the clamped [`Delta`], the number of loops gliding along
+/// boundary planes which is comparable with [`Clamp::loops`], and the number of bisections used
+/// for [`Delta::First`] and the non-gliding [`Delta::Scale`]. This is used by [`TrackballPlugin`]
+/// for [`TrackballCamera::clamp`].
+///
+/// ```
+/// use bevy_trackball::{
+/// 	clamps::{self, HalfSpace},
+/// 	prelude::*,
+/// };
+///
+/// let clamp = HalfSpace::new(Plane::new(Vector3::y_axis(), 0.0));
+/// let frame = Frame::look_at(
+/// 	Point3::new(0.0, -4.0, 0.0),
+/// 	&Point3::new(0.0, 2.0, 8.0),
+/// 	&Vector3::y(),
+/// );
+/// let scope = Scope::default();
+///
+/// // Zooming in towards the target below the ground plane.
+/// let delta = Delta::Scale {
+/// 	rat: 0.5,
+/// 	pos: Point3::origin(),
+/// };
+/// let (delta, _loops, _bisections) = clamps::compute(&clamp, &frame, &scope, &delta).unwrap();
+/// let new_frame = delta.transform(&frame);
+///
+/// // Glides along the ground plane preserving the zoom ratio.
+/// assert_abs_diff_eq!(new_frame.eye().y, 0.0, epsilon = 1e-3);
+/// assert_abs_diff_eq!(new_frame.distance(), 5.0, epsilon = 1e-3);
+/// ```
+///
+/// [`TrackballPlugin`]: crate::TrackballPlugin
+/// [`TrackballCamera::clamp`]: crate::TrackballCamera::clamp
+#[must_use]
+pub fn compute(
+	clamp: &dyn Clamp<f32>,
+	frame: &Frame<f32>,
+	scope: &Scope<f32>,
+	delta: &Delta<f32>,
+) -> Option<(Delta<f32>, usize, usize)> {
+	match *delta {
+		Delta::First {
+			pitch,
+			yaw,
+			yaw_axis,
+		} => {
+			if !exceeded(clamp, &delta.transform(frame)) {
+				return None;
+			}
+			let first = |pitch, yaw| Delta::First {
+				pitch,
+				yaw,
+				yaw_axis,
+			};
+			let satisfied = |pitch, yaw| !exceeded(clamp, &first(pitch, yaw).transform(frame));
+			let candidates = [
+				bisect(|t| satisfied(pitch * t, yaw)).map(|(t, loops)| (pitch * t, yaw, loops)),
+				bisect(|t| satisfied(pitch, yaw * t)).map(|(t, loops)| (pitch, yaw * t, loops)),
+				bisect(|t| satisfied(pitch * t, yaw * t))
+					.map(|(t, loops)| (pitch * t, yaw * t, loops)),
+			];
+			let bisections = candidates
+				.iter()
+				.flatten()
+				.map(|&(_, _, bisections)| bisections)
+				.sum();
+			let (pitch, yaw, _bisections) = candidates
+				.into_iter()
+				.flatten()
+				.max_by(|(a, b, _), (c, d, _)| (a.abs() + b.abs()).total_cmp(&(c.abs() + d.abs())))
+				.unwrap_or_default();
+			Some((first(pitch, yaw), 0, bisections))
+		}
+		Delta::Scale { rat, pos } => {
+			if exceeded(clamp, &delta.transform(frame)) {
+				let (min_delta, loops, bisections) = glide_scale(clamp, frame, rat).map_or_else(
+					|| {
+						let (t, bisections) = bisect(|t| {
+							let delta = Delta::Scale {
+								rat: rat.powf(t),
+								pos,
+							};
+							!exceeded(clamp, &delta.transform(frame))
+						})
+						.unwrap_or_default();
+						let rat = rat.powf(t);
+						(Delta::Scale { rat, pos }, 0, bisections)
+					},
+					|(min_delta, loops)| (min_delta, loops, 0),
+				);
+				clamp
+					.compute(frame, scope, &min_delta)
+					.map_or(Some((min_delta, loops, bisections)), |(delta, loops)| {
+						Some((delta, loops, bisections))
+					})
+			} else {
+				clamp
+					.compute(frame, scope, delta)
+					.map(|(delta, loops)| (delta, loops, 0))
+			}
+		}
+		_ => clamp
+			.compute(frame, scope, delta)
+			.map(|(delta, loops)| (delta, loops, 0)),
+	}
+}
+
+/// Boundary condition of [`Clamp`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Constraint {
+	/// Boundary condition of target position, see [`Clamp::target`].
+	Target,
+	/// Boundary condition of eye position, see [`Clamp::eye`].
+	Eye,
+	/// Boundary condition of up position, see [`Clamp::up`].
+	Up,
+}
+
+/// Exceeded boundary condition of `clamp` for `frame` if any, checked in the order of
+/// [`Constraint`] variants.
+#[must_use]
+pub fn constraint(clamp: &dyn Clamp<f32>, frame: &Frame<f32>) -> Option<Constraint> {
+	if clamp.target(frame).is_some() {
+		Some(Constraint::Target)
+	} else if clamp.eye(frame).is_some() {
+		Some(Constraint::Eye)
+	} else if clamp.up(frame).is_some() {
+		Some(Constraint::Up)
+	} else {
+		None
+	}
+}
+
+/// Whether any boundary condition is exceeded.
+fn exceeded(clamp: &dyn Clamp<f32>, frame: &Frame<f32>) -> bool {
+	constraint(clamp, frame).is_some()
+}
+
+/// Maximum fraction from `0.0` to `1.0` satisfying boundary conditions if `0.0` does.
+///
+/// Returns the fraction and the number of bisections used, which is zero if `1.0` is satisfied.
+fn bisect(satisfied: impl Fn(f32) -> bool) -> Option<(f32, usize)> {
+	if !satisfied(0.0) {
+		return None;
+	}
+	if satisfied(1.0) {
+		return Some((1.0, 0));
+	}
+	let (mut min, mut max) = (0.0, 1.0);
+	for _ in 0..BISECTIONS {
+		let mid = (min + max) * 0.5;
+		if satisfied(mid) {
+			min = mid;
+		} else {
+			max = mid;
+		}
+	}
+	Some((min, BISECTIONS))
+}
+
+/// Glides scale by `rat` along boundary planes by changing its center.
+///
+/// A homothety with ratio `rat` moving position `old` to `new` is centered at
+/// `(old * rat - new) / (rat - 1)`.
+fn glide_scale(
+	clamp: &dyn Clamp<f32>,
+	frame: &Frame<f32>,
+	rat: f32,
+) -> Option<(Delta<f32>, usize)> {
+	if (rat - 1.0).abs() <= f32::default_epsilon() {
+		return None;
+	}
+	let rot_inverse = frame.view().rotation.inverse();
+	let (target, eye) = (*frame.target(), frame.eye());
+	let mut min_delta = Delta::Scale {
+		rat,
+		pos: Point3::origin(),
+	};
+	let mut loops = 0;
+	loop {
+		let new_frame = min_delta.transform(frame);
+		let (old, new) = if let Some(plane) = clamp.target(&new_frame) {
+			(target, plane.project_point(new_frame.target()))
+		} else if let Some(plane) = clamp.eye(&new_frame) {
+			(eye, plane.project_point(&new_frame.eye()))
+		} else if clamp.up(&new_frame).is_some() {
+			return None;
+		} else {
+			return Some((min_delta, loops));
+		};
+		if loops == clamp.loops() {
+			return None;
+		}
+		loops += 1;
+		let pos = (old.coords * rat - new.coords) / (rat - 1.0);
+		min_delta = Delta::Scale {
+			rat,
+			pos: rot_inverse * (Point3::from(pos) - target.coords),
+		};
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::f32::consts::{FRAC_PI_4, FRAC_PI_6};
+
+	use trackball::{
+		approx::assert_abs_diff_eq,
+		nalgebra::{UnitQuaternion, Vector3},
+	};
+
+	use super::*;
+
+	fn look_at(target: [f32; 3], eye: [f32; 3]) -> Frame<f32> {
+		Frame::look_at(target.into(), &eye.into(), &Vector3::y())
+	}
+
+	fn glide(clamp: &dyn Clamp<f32>, frame: &Frame<f32>, delta: &Delta<f32>) -> Frame<f32> {
+		let (delta, _loops, _bisections) = compute(clamp, frame, &Scope::default(), delta).unwrap();
+		let new_frame = delta.transform(frame);
+		assert!(!exceeded(clamp, &new_frame));
+		new_frame
+	}
+
+	fn orbit(axis: [f32; 3], angle: f32) -> Delta<f32> {
+		Delta::Orbit {
+			rot: UnitQuaternion::from_axis_angle(&Unit::new_normalize(axis.into()), angle),
+			pos: Point3::origin(),
+		}
+	}
+
+	fn slide(vec: [f32; 3]) -> Delta<f32> {
+		Delta::Slide { vec: vec.into() }
+	}
+
+	#[test]
+	fn distance() {
+		let clamp = Distance::new(2.0, 50.0);
+		let frame = look_at([0.0; 3], [0.0, 0.0, 10.0]);
+		for (rat, bound) in [(0.1, 2.0), (10.0, 50.0)] {
+			let delta = Delta::Scale {
+				rat,
+				pos: Point3::origin(),
+			};
+			let frame = glide(&clamp, &frame, &delta);
+			assert_abs_diff_eq!(frame.distance(), bound, epsilon = bound * 1e-3);
+			assert_abs_diff_eq!(frame.roll_axis(), Vector3::z_axis(), epsilon = 1e-3);
+		}
+		let delta = orbit([0.0, 1.0, 0.0], FRAC_PI_4);
+		assert!(compute(&clamp, &frame, &Scope::default(), &delta).is_none());
+	}
+
+	#[test]
+	fn pitch() {
+		let clamp = Pitch::new(Vector3::y_axis(), -FRAC_PI_6, FRAC_PI_6);
+		let frame = look_at([0.0; 3], [0.0, 0.0, 10.0]);
+		for (sign, bound) in [(1.0, 5.0), (-1.0, -5.0)] {
+			let delta = orbit([-sign, -1.0, 0.0], FRAC_PI_4 * 1.5);
+			let frame = glide(&clamp, &frame, &delta);
+			let eye = frame.eye();
+			assert_abs_diff_eq!(eye.y, bound, epsilon = 1e-3);
+			assert_abs_diff_eq!(frame.distance(), 10.0, epsilon = 1e-3);
+			assert!(eye.x < -1.0);
+		}
+	}
+
+	#[test]
+	fn target_box() {
+		let clamp = TargetBox::new(Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0));
+		let frame = look_at([0.0; 3], [0.0, 0.0, 10.0]);
+		let face = glide(&clamp, &frame, &slide([2.0, 0.5, 0.0]));
+		assert_abs_diff_eq!(*face.target(), Point3::new(1.0, 0.5, 0.0), epsilon = 1e-3);
+		let edge = glide(&clamp, &frame, &slide([2.0, -3.0, 0.0]));
+		assert_abs_diff_eq!(*edge.target(), Point3::new(1.0, -1.0, 0.0), epsilon = 1e-3);
+		let first = Delta::First {
+			pitch: -FRAC_PI_4,
+			yaw: 0.1,
+			yaw_axis: Vector3::y_axis(),
+		};
+		let first = glide(&clamp, &frame, &first);
+		assert_abs_diff_eq!(first.eye(), frame.eye(), epsilon = 1e-3);
+		assert!(first.target().x < -0.5);
+	}
+
+	#[test]
+	fn eye_sphere() {
+		let clamp = EyeSphere::new(Point3::origin(), 10.0);
+		let frame = look_at([0.0; 3], [0.0, 0.0, 10.0]);
+		let frame = glide(&clamp, &frame, &slide([5.0, 0.0, -1.0]));
+		let eye = frame.eye();
+		assert_abs_diff_eq!(eye.coords.norm(), 10.0, epsilon = 1e-3);
+		assert!(eye.x > 1.0);
+	}
+
+	#[test]
+	fn half_space() {
+		let clamp = HalfSpace::new(Plane::new(Vector3::y_axis(), 0.0));
+		let frame = look_at([0.0, 1.0, 0.0], [0.0, 1.0, 10.0]);
+		let slided = glide(&clamp, &frame, &slide([2.0, -2.0, 0.0]));
+		assert_abs_diff_eq!(slided.eye(), Point3::new(2.0, 0.0, 10.0), epsilon = 1e-3);
+		let orbited = glide(&clamp, &frame, &orbit([1.0, -1.0, 0.0], FRAC_PI_4));
+		let eye = orbited.eye();
+		assert_abs_diff_eq!(eye.y, 0.0, epsilon = 1e-3);
+		assert_abs_diff_eq!(orbited.distance(), 10.0, epsilon = 1e-3);
+		assert!(eye.x < -1.0);
+	}
+
+	#[test]
+	fn all() {
+		let clamp = All::default()
+			.with(HalfSpace::new(Plane::new(Vector3::y_axis(), 0.0)))
+			.with(TargetBox::new(
+				Point3::new(-1.0, 0.5, -1.0),
+				Point3::new(1.0, 2.0, 1.0),
+			));
+		let frame = look_at([0.0, 1.0, 0.0], [0.0, 1.0, 10.0]);
+		let frame = glide(&clamp, &frame, &slide([2.0, -2.0, 0.0]));
+		assert_abs_diff_eq!(*frame.target(), Point3::new(1.0, 0.5, 0.0), epsilon = 1e-3);
+		assert_abs_diff_eq!(frame.eye(), Point3::new(1.0, 0.5, 10.0), epsilon = 1e-3);
+		let clamp = All::default()
+			.with(Distance::new(2.0, 50.0))
+			.with(Pitch::new(Vector3::y_axis(), -FRAC_PI_6, FRAC_PI_6));
+		assert_eq!(clamp.loops(), 100);
+		let frame = look_at([0.0; 3], [0.0, 0.0, 10.0]);
+		let orbited = glide(&clamp, &frame, &orbit([-1.0, -1.0, 0.0], FRAC_PI_4 * 1.5));
+		assert_abs_diff_eq!(orbited.eye().y, 5.0, epsilon = 1e-3);
+		assert!(orbited.eye().x < -1.0);
+		let delta = Delta::Scale {
+			rat: 0.1,
+			pos: Point3::origin(),
+		};
+		let scaled = glide(&clamp, &orbited, &delta);
+		assert_abs_diff_eq!(scaled.distance(), 2.0, epsilon = 1e-3);
+	}
+	#[test]
+	fn limits() {
+		let mut bound = Bound {
+			transform: Isometry3::translation(0.0, 1.0, 0.0),
+			..Bound::default()
+		};
+		bound.min_eye.y = 0.5;
+		bound.max_distance = 20.0;
+		assert_eq!(
+			bound.limits(),
+			[
+				Limit::Plane(Plane::new(Vector3::y_axis(), 1.5)),
+				Limit::Distance {
+					min: 0.0,
+					max: 20.0
+				},
+			]
+		);
+		bound.min_target = Point3::new(-1.0, -1.0, -1.0);
+		bound.max_target = Point3::new(1.0, 1.0, 1.0);
+		assert_eq!(
+			bound.limits()[0],
+			Limit::Box {
+				transform: bound.transform,
+				min: bound.min_target,
+				max: bound.max_target,
+			}
+		);
+		let clamp = All::default()
+			.with(Bound::default())
+			.with_limits(EyeSphere::new(Point3::origin(), 10.0));
+		assert_eq!(clamp.clamps.len(), 2);
+		assert_eq!(
+			clamp.limits(),
+			[Limit::Sphere {
+				center: Point3::origin(),
+				radius: 10.0
+			}]
+		);
+	}
+}
@@ -0,0 +1,564 @@
+use std::{
+	collections::HashMap,
+	fmt,
+	ops::Range,
+	sync::{Arc, PoisonError, RwLock},
+};
+
+use bevy::{
+	math::bounding::{Aabb3d, RayCast3d},
+	prelude::*,
+};
+use trackball::{
+	Clamp, Delta, Frame, Plane, Scope,
+	nalgebra::{Point3, Unit, Vector3},
+};
+
+use super::hysteresis;
+use crate::TrackballSystemSet;
+
+/// Maximum number of triangles per leaf of the bounding volume hierarchy of a collider.
+const LEAF: usize = 4;
+
+/// Plugin keeping [`Colliders`] up to date with entities marked as [`Collider`].
+///
+/// Only required for [`Collision`] clamps and [`TrackballGround::colliders`], so apps not using
+/// them spend nothing on collision geometry.
+///
+/// [`TrackballGround::colliders`]: crate::TrackballGround::colliders
+#[derive(Default)]
+pub struct TrackballCollidersPlugin;
+
+impl Plugin for TrackballCollidersPlugin {
+	fn build(&self, app: &mut App) {
+		app.init_resource::<Colliders>().add_systems(
+			Update,
+			trackball_colliders.before(TrackballSystemSet::Constellation),
+		);
+	}
+}
+
+/// Marks entities with [`Mesh3d`] as colliders of [`Collision`] clamps.
+///
+/// Their triangles are transformed into world space whenever their [`Mesh3d`] or
+/// [`GlobalTransform`] changes. Later modifications of the mesh asset itself are not tracked.
+#[derive(Component, Debug, Default, Clone, Copy)]
+pub struct Collider;
+
+/// Collision geometry in world space shared between [`Collision`] clamps.
+///
+/// Added as resource by [`TrackballCollidersPlugin`] and kept up to date with entities marked as
+/// [`Collider`]. Geometry can be inserted manually as well, e.g., for collision geometry which is
+/// simpler than its rendered mesh. Each collider is indexed by a bounding volume hierarchy, so ray
+/// casts and proximity queries only test triangles near the ray or position.
+#[derive(Resource, Clone, Default)]
+pub struct Colliders(Arc<RwLock<HashMap<Entity, Shape>>>);
+
+impl Colliders {
+	/// Collision clamp keeping eye sphere of `radius` outside of these colliders.
+	#[must_use]
+	pub fn clamp(&self, radius: f32) -> Collision {
+		Collision {
+			colliders: self.clone(),
+			radius,
+			hysteresis: hysteresis(),
+		}
+	}
+	/// Inserts or replaces collider of `entity` with `triangles` in world space.
+	pub fn insert(&self, entity: Entity, triangles: impl IntoIterator<Item = Triangle3d>) {
+		let shape = Shape::new(triangles.into_iter().collect());
+		self.0
+			.write()
+			.unwrap_or_else(PoisonError::into_inner)
+			.insert(entity, shape);
+	}
+	/// Removes collider of `entity`.
+	pub fn remove(&self, entity: Entity) {
+		self.0
+			.write()
+			.unwrap_or_else(PoisonError::into_inner)
+			.remove(&entity);
+	}
+	/// Whether there is a collider of `entity`.
+	#[must_use]
+	pub fn contains(&self, entity: Entity) -> bool {
+		self.0
+			.read()
+			.unwrap_or_else(PoisonError::into_inner)
+			.contains_key(&entity)
+	}
+	/// Distance from `origin` along `dir` to the closest collider hit by this ray.
+	///
+	/// Serves as ground of walk mode, see [`TrackballGround::colliders`].
+	///
+	/// [`TrackballGround::colliders`]: crate::TrackballGround::colliders
+	#[must_use]
+	pub fn cast_ray(&self, origin: Vec3, dir: Dir3) -> Option<f32> {
+		let ray = RayCast3d::new(origin, dir, f32::MAX);
+		let shapes = self.0.read().unwrap_or_else(PoisonError::into_inner);
+		shapes
+			.values()
+			.fold(None, |distance, shape| shape.cast_ray(&ray, distance))
+	}
+	/// Closest point on colliders within `radius` around `pos` and the normal of its triangle.
+	fn closest(&self, pos: Vec3, radius: f32) -> Option<(Vec3, Vec3)> {
+		let shapes = self.0.read().unwrap_or_else(PoisonError::into_inner);
+		shapes
+			.values()
+			.fold(None, |closest, shape| shape.closest(pos, radius, closest))
+			.map(|(point, triangle)| {
+				let normal = triangle.normal().map_or(Vec3::ZERO, |normal| *normal);
+				(point, normal)
+			})
+	}
+}
+
+impl fmt::Debug for Colliders {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let shapes = self.0.read().unwrap_or_else(PoisonError::into_inner);
+		f.debug_struct("Colliders")
+			.field("len", &shapes.len())
+			.finish_non_exhaustive()
+	}
+}
+
+/// Triangles in world space with their bounding volume hierarchy.
+#[derive(Debug, Clone)]
+struct Shape {
+	/// Nodes where the first one is the root if any.
+	nodes: Vec<Node>,
+	/// Triangles ordered by the leaves of the hierarchy.
+	triangles: Vec<Triangle3d>,
+}
+
+/// Node of bounding volume hierarchy.
+#[derive(Debug, Clone)]
+struct Node {
+	/// Bounding box of its triangles.
+	aabb: Aabb3d,
+	/// Range of its triangles.
+	triangles: Range<usize>,
+	/// Indices of its child nodes or `None` if it is a leaf.
+	children: Option<[usize; 2]>,
+}
+
+impl Shape {
+	/// Builds bounding volume hierarchy of `triangles` by splitting at the median centroid along
+	/// the longest axis.
+	fn new(mut triangles: Vec<Triangle3d>) -> Self {
+		let mut nodes = Vec::new();
+		if !triangles.is_empty() {
+			Self::build(&mut nodes, &mut triangles, 0);
+		}
+		Self { nodes, triangles }
+	}
+	/// Builds node of `triangles` starting at `offset` and returns its index.
+	fn build(nodes: &mut Vec<Node>, triangles: &mut [Triangle3d], offset: usize) -> usize {
+		let (min, max) = triangles
+			.iter()
+			.flat_map(|triangle| triangle.vertices)
+			.fold((Vec3::MAX, Vec3::MIN), |(min, max), vertex| {
+				(min.min(vertex), max.max(vertex))
+			});
+		let index = nodes.len();
+		nodes.push(Node {
+			aabb: Aabb3d {
+				min: min.into(),
+				max: max.into(),
+			},
+			triangles: offset..offset + triangles.len(),
+			children: None,
+		});
+		if triangles.len() > LEAF {
+			let axis = (max - min).max_position();
+			let mid = triangles.len() / 2;
+			triangles.select_nth_unstable_by(mid, |a, b| {
+				a.centroid()[axis].total_cmp(&b.centroid()[axis])
+			});
+			let (left, right) = triangles.split_at_mut(mid);
+			let left = Self::build(nodes, left, offset);
+			let right = Self::build(nodes, right, offset + mid);
+			nodes[index].children = Some([left, right]);
+		}
+		index
+	}
+	/// Distance along `ray` to the closest triangle hit if closer than `distance`.
+	fn cast_ray(&self, ray: &RayCast3d, mut distance: Option<f32>) -> Option<f32> {
+		let mut stack = Vec::from_iter((!self.nodes.is_empty()).then_some(0));
+		while let Some(index) = stack.pop() {
+			let node = &self.nodes[index];
+			let Some(entry) = ray.aabb_intersection_at(&node.aabb) else {
+				continue;
+			};
+			if distance.is_some_and(|distance| entry > distance) {
+				continue;
+			}
+			if let Some(children) = node.children {
+				stack.extend(children);
+			} else {
+				let (origin, dir) = (ray.origin.into(), (*ray.direction).into());
+				for triangle in &self.triangles[node.triangles.clone()] {
+					if let Some(hit) = intersect_ray(triangle, origin, dir)
+						&& distance.is_none_or(|distance| hit < distance)
+					{
+						distance = Some(hit);
+					}
+				}
+			}
+		}
+		distance
+	}
+	/// Closest point on triangles within `radius` around `pos` if closer than `closest`.
+	fn closest<'a>(
+		&'a self,
+		pos: Vec3,
+		radius: f32,
+		mut closest: Option<(Vec3, &'a Triangle3d)>,
+	) -> Option<(Vec3, &'a Triangle3d)> {
+		let max = |closest: Option<(Vec3, _)>| {
+			closest.map_or(radius * radius, |(point, _)| point.distance_squared(pos))
+		};
+		let mut stack = Vec::from_iter((!self.nodes.is_empty()).then_some(0));
+		while let Some(index) = stack.pop() {
+			let node = &self.nodes[index];
+			let aabb = &node.aabb;
+			let nearest = pos.clamp(aabb.min.into(), aabb.max.into());
+			if nearest.distance_squared(pos) >= max(closest) {
+				continue;
+			}
+			if let Some(children) = node.children {
+				stack.extend(children);
+			} else {
+				for triangle in &self.triangles[node.triangles.clone()] {
+					let point = closest_point(triangle, pos);
+					if point.distance_squared(pos) < max(closest) {
+						closest = Some((point, triangle));
+					}
+				}
+			}
+		}
+		closest
+	}
+}
+
+/// Eye sphere kept outside of [`Colliders`] (e.g., walls of interior architecture scenes).
+///
+/// The boundary plane is tangent to the eye sphere at the closest point on any collider, so the eye
+/// glides along walls instead of passing through them. Movements crossing geometry within a single
+/// frame are detected by casting a ray from the old to the new eye position and stop the eye sphere
+/// in front of the first hit, so the eye does not tunnel through thin geometry.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_trackball::{
+/// 	clamps::{Colliders, Collision},
+/// 	prelude::*,
+/// };
+///
+/// // Wall in the xy-plane.
+/// let colliders = Colliders::default();
+/// let [a, b, c, d] = [
+/// 	Vec3::new(-10.0, -10.0, 0.0),
+/// 	Vec3::new(10.0, -10.0, 0.0),
+/// 	Vec3::new(10.0, 10.0, 0.0),
+/// 	Vec3::new(-10.0, 10.0, 0.0),
+/// ];
+/// colliders.insert(
+/// 	Entity::PLACEHOLDER,
+/// 	[Triangle3d::new(a, b, c), Triangle3d::new(a, c, d)],
+/// );
+/// let clamp = colliders.clamp(0.5);
+/// let frame = Frame::look_at(
+/// 	Point3::new(0.0, 0.0, -4.0),
+/// 	&Point3::new(0.0, 0.0, 2.0),
+/// 	&Vector3::y(),
+/// );
+/// let scope = Scope::default();
+///
+/// // Sliding diagonally towards the wall.
+/// let delta = Delta::Slide {
+/// 	vec: Vector3::new(1.0, 0.0, -1.8),
+/// };
+/// let (delta, _loops) = clamp.compute(&frame, &scope, &delta).unwrap();
+/// let eye = delta.transform(&frame).eye();
+///
+/// // Glides along the wall keeping the radius.
+/// assert_abs_diff_eq!(eye, Point3::new(1.0, 0.0, 0.5), epsilon = 1e-3);
+///
+/// // Sliding through the wall within a single frame.
+/// let delta = Delta::Slide {
+/// 	vec: Vector3::new(0.0, 0.0, -10.0),
+/// };
+/// let (delta, _loops) = clamp.compute(&frame, &scope, &delta).unwrap();
+/// let eye = delta.transform(&frame).eye();
+///
+/// // Stops in front of the wall keeping the radius.
+/// assert_abs_diff_eq!(eye, Point3::new(0.0, 0.0, 0.5), epsilon = 1e-3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Collision {
+	colliders: Colliders,
+	/// Radius of eye sphere.
+	pub radius: f32,
+	/// Epsilon allowing clamped deltas to more likely pass revalidation.
+	pub hysteresis: f32,
+}
+
+impl Clamp<f32> for Collision {
+	fn target(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+	fn eye(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		let eye = Vec3::from(frame.eye());
+		let radius = self.radius - self.hysteresis;
+		let (point, normal) = self.colliders.closest(eye, radius)?;
+		let vec = eye - point;
+		let normal = if vec.length_squared() > f32::EPSILON {
+			vec.normalize()
+		} else {
+			normal
+		};
+		let normal = Unit::try_new(Vector3::from(normal), f32::EPSILON)?;
+		let point = Point3::from(point) + normal.into_inner() * self.radius;
+		Some(Plane::with_point(normal, &point))
+	}
+	fn up(&self, _frame: &Frame<f32>) -> Option<Plane<f32>> {
+		None
+	}
+	fn compute(
+		&self,
+		frame: &Frame<f32>,
+		scope: &Scope<f32>,
+		delta: &Delta<f32>,
+	) -> Option<(Delta<f32>, usize)> {
+		let proximity = Proximity(self.clone());
+		let old_eye = Vec3::from(frame.eye());
+		let new_eye = Vec3::from(delta.transform(frame).eye());
+		let Some(fraction) = Dir3::new_and_length(new_eye - old_eye)
+			.ok()
+			.and_then(|(dir, len)| {
+				let distance = self.colliders.cast_ray(old_eye, dir)?;
+				(distance <= len).then(|| ((distance - self.radius) / len).max(0.0))
+			})
+		else {
+			return proximity.compute(frame, scope, delta);
+		};
+		let delta = delta.lerp_slerp(fraction);
+		proximity
+			.compute(frame, scope, &delta)
+			.map_or(Some((delta, 1)), |(delta, loops)| Some((delta, loops + 1)))
+	}
+}
+
+/// Proximity test of [`Collision`] gliding along the tangent planes without ray casting.
+#[derive(Debug)]
+struct Proximity(Collision);
+
+impl Clamp<f32> for Proximity {
+	fn loops(&self) -> usize {
+		self.0.loops()
+	}
+	fn target(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		self.0.target(frame)
+	}
+	fn eye(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		self.0.eye(frame)
+	}
+	fn up(&self, frame: &Frame<f32>) -> Option<Plane<f32>> {
+		self.0.up(frame)
+	}
+}
+
+/// Distance from `origin` along `dir` to `triangle` if hit (Möller–Trumbore).
+fn intersect_ray(triangle: &Triangle3d, origin: Vec3, dir: Vec3) -> Option<f32> {
+	let [a, b, c] = triangle.vertices;
+	let (ab, ac) = (b - a, c - a);
+	let pvec = dir.cross(ac);
+	let det = ab.dot(pvec);
+	if det.abs() <= f32::EPSILON {
+		return None;
+	}
+	let inv = det.recip();
+	let tvec = origin - a;
+	let bu = tvec.dot(pvec) * inv;
+	if !(0.0..=1.0).contains(&bu) {
+		return None;
+	}
+	let qvec = tvec.cross(ab);
+	let bv = dir.dot(qvec) * inv;
+	if bv < 0.0 || bu + bv > 1.0 {
+		return None;
+	}
+	let distance = ac.dot(qvec) * inv;
+	(distance >= 0.0).then_some(distance)
+}
+
+/// Closest point on `triangle` to `pos`.
+fn closest_point(triangle: &Triangle3d, pos: Vec3) -> Vec3 {
+	let [a, b, c] = triangle.vertices;
+	let (ab, ac, ap) = (b - a, c - a, pos - a);
+	let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+	if d1 <= 0.0 && d2 <= 0.0 {
+		return a;
+	}
+	let bp = pos - b;
+	let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+	if d3 >= 0.0 && d4 <= d3 {
+		return b;
+	}
+	let vc = d1.mul_add(d4, -d3 * d2);
+	if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+		return a + ab * (d1 / (d1 - d3));
+	}
+	let cp = pos - c;
+	let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+	if d6 >= 0.0 && d5 <= d6 {
+		return c;
+	}
+	let vb = d5.mul_add(d2, -d1 * d6);
+	if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+		return a + ac * (d2 / (d2 - d6));
+	}
+	let va = d3.mul_add(d6, -d5 * d4);
+	if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+		return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+	}
+	let denom = (va + vb + vc).recip();
+	a + ab * (vb * denom) + ac * (vc * denom)
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn trackball_colliders(
+	colliders: Res<Colliders>,
+	meshes: Option<Res<Assets<Mesh>>>,
+	query: Query<(Entity, Ref<Mesh3d>, Ref<GlobalTransform>), With<Collider>>,
+	mut removed: RemovedComponents<Collider>,
+) {
+	for entity in removed.read() {
+		colliders.remove(entity);
+	}
+	let Some(meshes) = meshes else {
+		return;
+	};
+	for (entity, mesh, transform) in &query {
+		if !mesh.is_changed() && !transform.is_changed() && colliders.contains(entity) {
+			continue;
+		}
+		let Some(Ok(triangles)) = meshes.get(&mesh.0).map(Mesh::triangles) else {
+			continue;
+		};
+		let triangles = triangles.map(|triangle| {
+			Triangle3d::new(
+				transform.transform_point(triangle.vertices[0]),
+				transform.transform_point(triangle.vertices[1]),
+				transform.transform_point(triangle.vertices[2]),
+			)
+		});
+		colliders.insert(entity, triangles);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use trackball::approx::assert_abs_diff_eq;
+
+	use super::*;
+
+	fn triangle() -> Triangle3d {
+		Triangle3d::new(Vec3::ZERO, Vec3::X, Vec3::Y)
+	}
+
+	/// Ground grid of `n` by `n` unit squares in the xz-plane.
+	fn grid(n: i16) -> Vec<Triangle3d> {
+		(0..n)
+			.flat_map(|x| (0..n).map(move |z| (f32::from(x), f32::from(z))))
+			.flat_map(|(x, z)| {
+				let corners = [
+					Vec3::new(x, 0.0, z),
+					Vec3::new(x + 1.0, 0.0, z),
+					Vec3::new(x + 1.0, 0.0, z + 1.0),
+					Vec3::new(x, 0.0, z + 1.0),
+				];
+				[
+					Triangle3d::new(corners[0], corners[1], corners[2]),
+					Triangle3d::new(corners[0], corners[2], corners[3]),
+				]
+			})
+			.collect()
+	}
+
+	#[test]
+	fn intersect() {
+		let triangle = triangle();
+		let hit = intersect_ray(&triangle, Vec3::new(0.2, 0.2, 2.0), Vec3::NEG_Z);
+		assert_abs_diff_eq!(hit.unwrap(), 2.0);
+		// Hits back faces.
+		let hit = intersect_ray(&triangle, Vec3::new(0.2, 0.2, -1.0), Vec3::Z);
+		assert_abs_diff_eq!(hit.unwrap(), 1.0);
+		// Misses outside, behind, and parallel.
+		assert!(intersect_ray(&triangle, Vec3::new(0.8, 0.8, 2.0), Vec3::NEG_Z).is_none());
+		assert!(intersect_ray(&triangle, Vec3::new(0.2, 0.2, 2.0), Vec3::Z).is_none());
+		assert!(intersect_ray(&triangle, Vec3::new(0.2, 0.2, 2.0), Vec3::X).is_none());
+	}
+
+	#[test]
+	fn closest() {
+		let triangle = triangle();
+		// Face, vertex, and edge regions.
+		let point = closest_point(&triangle, Vec3::new(0.2, 0.3, 5.0));
+		assert!(point.abs_diff_eq(Vec3::new(0.2, 0.3, 0.0), 1e-6));
+		let point = closest_point(&triangle, Vec3::new(-1.0, -1.0, 1.0));
+		assert!(point.abs_diff_eq(Vec3::ZERO, 1e-6));
+		let point = closest_point(&triangle, Vec3::new(2.0, -0.5, 0.0));
+		assert!(point.abs_diff_eq(Vec3::X, 1e-6));
+		let point = closest_point(&triangle, Vec3::new(0.5, -1.0, 0.0));
+		assert!(point.abs_diff_eq(Vec3::new(0.5, 0.0, 0.0), 1e-6));
+		let point = closest_point(&triangle, Vec3::new(1.0, 1.0, 0.0));
+		assert!(point.abs_diff_eq(Vec3::new(0.5, 0.5, 0.0), 1e-6));
+	}
+
+	#[test]
+	fn hierarchy() {
+		let triangles = grid(16);
+		let shape = Shape::new(triangles.clone());
+		assert_eq!(shape.triangles.len(), triangles.len());
+		assert!(shape.nodes.len() > 1);
+		for node in &shape.nodes {
+			for triangle in &shape.triangles[node.triangles.clone()] {
+				for vertex in triangle.vertices {
+					assert!(node.aabb.min.cmple(vertex.into()).all());
+					assert!(node.aabb.max.cmpge(vertex.into()).all());
+				}
+			}
+		}
+		let colliders = Colliders::default();
+		colliders.insert(Entity::PLACEHOLDER, triangles.clone());
+		// Ray casts agree with brute force.
+		for (origin, dir) in [
+			(Vec3::new(3.3, 2.0, 7.7), Dir3::NEG_Y),
+			(
+				Vec3::new(-1.0, 1.0, 5.5),
+				Dir3::new(Vec3::new(1.0, -0.1, 0.2)).unwrap(),
+			),
+			(Vec3::new(20.0, 1.0, 5.5), Dir3::NEG_Y),
+		] {
+			let brute = triangles
+				.iter()
+				.filter_map(|triangle| intersect_ray(triangle, origin, *dir))
+				.min_by(f32::total_cmp);
+			assert_eq!(colliders.cast_ray(origin, dir), brute);
+		}
+		// Proximity queries agree with brute force.
+		for pos in [Vec3::new(5.2, 0.4, 9.1), Vec3::new(-0.3, 0.2, 16.2)] {
+			let (point, normal) = colliders.closest(pos, 1.0).unwrap();
+			let brute = triangles
+				.iter()
+				.map(|triangle| closest_point(triangle, pos))
+				.min_by(|a, b| a.distance_squared(pos).total_cmp(&b.distance_squared(pos)))
+				.unwrap();
+			assert_abs_diff_eq!(point.distance(pos), brute.distance(pos), epsilon = 1e-6);
+			assert_abs_diff_eq!(normal.y.abs(), 1.0);
+		}
+		assert!(colliders.closest(Vec3::new(5.0, 2.0, 5.0), 1.0).is_none());
+	}
+}
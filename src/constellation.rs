@@ -1,34 +1,67 @@
 use bevy::prelude::*;
 
-use super::{TrackballCamera, TrackballMessage, TrackballSetup};
+use super::{
+	TrackballBookmarks, TrackballCamera, TrackballClampMessage, TrackballFlightMessage,
+	TrackballMessage, TrackballSetup, clamps,
+};
 
 const LOOPS: usize = 100;
 
 #[allow(clippy::needless_pass_by_value)]
 #[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_lines)]
 pub fn trackball_constellation(
 	mut trackball_events: MessageReader<TrackballMessage>,
-	mut cameras: Query<(Entity, &mut TrackballCamera)>,
+	mut cameras: Query<(
+		Entity,
+		&mut TrackballCamera,
+		Option<&mut TrackballBookmarks>,
+	)>,
+	mut flight_events: MessageWriter<TrackballFlightMessage>,
+	mut clamp_events: MessageWriter<TrackballClampMessage>,
 ) {
 	for trackball_event in trackball_events.read() {
 		let mut min_delta = trackball_event.delta;
+		let mut damped = Vec::new();
 		let mut loops = 0;
 		loop {
 			let mut bound = false;
-			for (group, mut trackball) in &mut cameras {
+			for (group, mut trackball, _bookmarks) in &mut cameras {
 				if group != trackball_event.group
 					&& !trackball.group.contains_key(&trackball_event.group)
 				{
 					continue;
 				}
+				let clamped = match (&trackball.clamp, trackball.elastic) {
+					(Some(clamp), None) => clamps::compute(
+						clamp.as_ref(),
+						&trackball.frame,
+						&trackball.scope,
+						&min_delta,
+					),
+					(Some(clamp), Some(elastic)) if !damped.contains(&group) => {
+						damped.push(group);
+						elastic
+							.compute(
+								clamp.as_ref(),
+								&trackball.frame,
+								&trackball.scope,
+								&min_delta,
+							)
+							.map(|delta| (delta, 0, 0))
+					}
+					_ => None,
+				};
 				if let Some(clamp) = &trackball.clamp
-					&& let Some((delta, loops)) =
-						clamp.compute(&trackball.frame, &trackball.scope, &min_delta)
+					&& let Some((delta, loops, bisections)) = clamped
 				{
 					if loops == clamp.loops() {
 						warn!("Using partial clamp after {loops} loops (entity {group:?})");
 					} else {
-						debug!("Found camera clamp after {loops} loops (entity {group:?})");
+						debug!(
+							"Found camera clamp after {loops} loops and {bisections} bisections \
+							 (entity {group:?})"
+						);
 					}
 					if trackball
 						.group
@@ -54,22 +87,41 @@ pub fn trackball_constellation(
 				break;
 			}
 		}
-		for (group, mut trackball) in &mut cameras {
+		for (group, mut trackball, mut bookmarks) in &mut cameras {
 			if group != trackball_event.group
 				&& !trackball.group.contains_key(&trackball_event.group)
 			{
 				continue;
 			}
-			let delta = if trackball
+			let (delta, clamped) = if trackball
 				.group
 				.get(&trackball_event.group)
 				.copied()
 				.unwrap_or(true)
 			{
-				min_delta
+				(min_delta, loops > 0)
+			} else if let Some(delta) = trackball.delta.take() {
+				(delta, true)
 			} else {
-				trackball.delta.take().unwrap_or(trackball_event.delta)
+				(trackball_event.delta, false)
 			};
+			if clamped || trackball.clamped {
+				clamp_events.write(TrackballClampMessage {
+					camera: group,
+					group: trackball_event.group,
+					frame: trackball.frame,
+					delta: trackball_event.delta,
+					clamped: clamped.then_some(delta),
+					partial: delta != trackball_event.delta,
+				});
+			}
+			trackball.clamped = clamped;
+			if trackball_event.user && trackball.flight.take().is_some() {
+				flight_events.write(TrackballFlightMessage {
+					camera: group,
+					arrived: false,
+				});
+			}
 			trackball.frame = delta.transform(&trackball.frame);
 			trackball.frame.renormalize();
 			if let Some(setup) = trackball_event.setup {
@@ -81,8 +133,67 @@ pub fn trackball_constellation(
 					TrackballSetup::Reset => {
 						trackball.frame = trackball.reset;
 					}
+					TrackballSetup::Target {
+						pos,
+						distance,
+						duration,
+					} => {
+						let frame = trackball.frame_target(pos, distance);
+						trackball.fly_to(frame, duration, EaseFunction::CubicInOut);
+					}
+					TrackballSetup::Bounds { aabb, margin } => {
+						trackball.frame = trackball.fit_aabb(&aabb, margin);
+					}
+					TrackballSetup::Sphere { sphere, margin } => {
+						trackball.frame = trackball.fit_sphere(&sphere, margin);
+					}
+					TrackballSetup::View(view) => {
+						let frame = trackball.frame_view(view);
+						let duration = trackball.view_duration;
+						trackball.fly_to(frame, duration, EaseFunction::CubicInOut);
+					}
+					TrackballSetup::Store(slot) => {
+						if let Some(bookmarks) = &mut bookmarks {
+							bookmarks.store(slot.to_string(), &trackball);
+						}
+					}
+					TrackballSetup::Recall(slot) => {
+						if let Some(bookmarks) = &bookmarks {
+							bookmarks.recall(&slot.to_string(), &mut trackball);
+						}
+					}
 				}
 			}
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use bevy::ecs::system::RunSystemOnce;
+	use trackball::nalgebra::Vector3;
+
+	use super::*;
+
+	#[test]
+	fn flight() {
+		let mut world = World::new();
+		world.init_resource::<Messages<TrackballMessage>>();
+		world.init_resource::<Messages<TrackballFlightMessage>>();
+		world.init_resource::<Messages<TrackballClampMessage>>();
+		let [target, eye, up] = [Vec3::ZERO, Vec3::Z * 10.0, Vec3::Y];
+		let mut trackball = TrackballCamera::look_at(target, eye, up);
+		let frame = TrackballCamera::look_at(target + Vec3::X, eye + Vec3::X, up).frame;
+		trackball.fly_to(frame, 500.0, EaseFunction::Linear);
+		let camera = world.spawn(trackball).id();
+		// Survives ground correction of walk mode.
+		let vec = Vector3::y() * 0.1;
+		world.write_message(TrackballMessage::slide(camera, vec).automatic());
+		world.run_system_once(trackball_constellation).unwrap();
+		assert!(world.get::<TrackballCamera>(camera).unwrap().is_flying());
+		// Canceled by user input.
+		world.write_message(TrackballMessage::slide(camera, vec));
+		world.run_system_once(trackball_constellation).unwrap();
+		assert!(!world.get::<TrackballCamera>(camera).unwrap().is_flying());
+	}
+}
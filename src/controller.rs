@@ -1,36 +1,72 @@
+#[cfg(feature = "bevy_picking")]
+use bevy::picking::{hover::HoverMap, pointer::PointerId};
 use bevy::{
 	camera::RenderTarget,
 	input::mouse::{MouseMotion, MouseWheel},
 	prelude::*,
 	window::{CursorGrabMode, CursorIcon, CursorOptions, PrimaryWindow, SystemCursorIcon},
 };
+use binding::Actions;
+pub use binding::{
+	TrackballAction, TrackballBinding, TrackballBindings, TrackballButton, TrackballModifiers,
+};
+use click::DoubleClick;
+use gamepad::gamepad;
+use inertia::Inertia;
 pub use input::{TrackballInput, TrackballVelocity, TrackballWheelUnit};
 use key::key;
+pub use mode::{TrackballGround, TrackballMode};
 use mouse::mouse;
+use snap::Snap;
 use touch::touch;
-use trackball::{First, Orbit, Scale, Slide, Touch};
+use trackball::{
+	First, Frame, Orbit, Scale, Slide, Touch,
+	nalgebra::{Point2, Point3, UnitQuaternion},
+};
 pub use viewport::TrackballViewport;
+use walk::walk;
 
 use super::{TrackballCamera, TrackballMessage};
 
+mod binding;
+mod click;
+mod gamepad;
+mod inertia;
 mod input;
 mod key;
+mod mode;
 mod mouse;
+mod snap;
 mod touch;
 mod viewport;
+mod walk;
 
 /// Trackball controller component mainly defined by [`TrackballInput`].
 #[derive(Component, Clone, Debug, Default)]
 pub struct TrackballController {
 	/// Input mappings and settings.
 	pub input: TrackballInput,
+	/// Camera mode. Default is [`TrackballMode::Trackball`].
+	pub mode: TrackballMode,
 
 	first: First<f32>,
 	orbit: Orbit<f32>,
 	scale: Scale<f32>,
 	slide: Slide<f32>,
+	zoom: Slide<f32>,
 	touch: Touch<Option<u64>, f32>,
+	pivot: Point3<f32>,
+	tilt: f32,
+	fly: bool,
+	fall: f32,
+	grounded: bool,
+	click: DoubleClick,
+	tap: DoubleClick,
+	snap: Snap,
+	gamer: Option<[Vec<TrackballBinding>; 6]>,
 
+	actions: Actions,
+	inertia: Inertia,
 	first_count: usize,
 }
 
@@ -49,16 +85,99 @@ impl TrackballController {
 		controller.input.map_wasd();
 		controller
 	}
+	/// Trackball controller in [`TrackballMode::fly`] using [`TrackballInput::map_fly`].
+	#[must_use]
+	pub fn map_fly() -> Self {
+		let mut controller = Self::default().with_mode(TrackballMode::fly());
+		controller.input.map_fly();
+		controller
+	}
+	/// Trackball controller in [`TrackballMode::walk`] using [`TrackballInput::map_walk`].
+	#[must_use]
+	pub fn map_walk(ground: TrackballGround, height: f32) -> Self {
+		let mut controller = Self::default().with_mode(TrackballMode::walk(ground, height));
+		controller.input.map_walk();
+		controller
+	}
+	/// Trackball controller using [`TrackballInput::map_blender`].
+	#[must_use]
+	pub fn map_blender() -> Self {
+		let mut controller = Self::default();
+		controller.input.map_blender();
+		controller
+	}
+	/// Trackball controller using [`TrackballInput::map_maya`].
+	#[must_use]
+	pub fn map_maya() -> Self {
+		let mut controller = Self::default();
+		controller.input.map_maya();
+		controller
+	}
+	/// Trackball controller using [`TrackballInput::map_cad`].
+	#[must_use]
+	pub fn map_cad() -> Self {
+		let mut controller = Self::default();
+		controller.input.map_cad();
+		controller
+	}
+	/// Defines camera mode, see [`Self::mode`].
+	#[must_use]
+	pub fn with_mode(mut self, mode: TrackballMode) -> Self {
+		self.mode = mode;
+		self
+	}
+	/// Whether any action is active or any finger is touching.
+	pub(crate) fn is_held(&self) -> bool {
+		self.actions.any_pressed() || self.touch.fingers() > 0
+	}
+	/// Whether inertia keeps coasting after release.
+	pub(crate) const fn is_coasting(&self) -> bool {
+		self.inertia.is_coasting()
+	}
+	/// Starts dragging at `pos` for orbiting.
+	fn capture(&mut self, pos: Point2<f32>, max: Point2<f32>) {
+		if matches!(self.mode, TrackballMode::Turntable { .. }) {
+			self.slide.compute(pos);
+		} else {
+			self.orbit.compute(&pos, &max);
+		}
+	}
+	/// Orbit rotation in camera space of dragging to `pos` and its part kept by inertia.
+	///
+	/// In turntable mode, only the yaw rotation is kept by inertia as it never exceeds the pitch
+	/// limits.
+	fn drag(
+		&mut self,
+		frame: &Frame<f32>,
+		pos: Point2<f32>,
+		max: Point2<f32>,
+		transmission: f32,
+	) -> Option<(UnitQuaternion<f32>, UnitQuaternion<f32>)> {
+		if !matches!(self.mode, TrackballMode::Turntable { .. }) {
+			return self.orbit.compute(&pos, &max).map(|rot| {
+				let rot = rot.powf(transmission);
+				(rot, rot)
+			});
+		}
+		let vec = self.slide.compute(pos)? * transmission;
+		let max = max.x.max(max.y) * 0.5;
+		let (yaw, pitch) = (-vec.x / max, -vec.y / max);
+		let rot = self.mode.orbit(frame, &mut self.tilt, yaw, pitch)?;
+		let spin = self.mode.orbit(frame, &mut self.tilt, yaw, 0.0)?;
+		Some((rot, spin))
+	}
 }
 
 #[allow(clippy::needless_pass_by_value)]
 #[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_lines)]
 pub fn trackball_controller(
 	mut commands: Commands,
 	mut viewport: ResMut<TrackballViewport>,
 	time: Res<Time>,
 	key_input: Res<ButtonInput<KeyCode>>,
 	mouse_input: Res<ButtonInput<MouseButton>>,
+	gamepads: Query<&Gamepad>,
 	mut touch_events: MessageReader<TouchInput>,
 	mut touch_events_clone: MessageReader<TouchInput>,
 	mut delta_events: MessageReader<MouseMotion>,
@@ -74,6 +193,7 @@ pub fn trackball_controller(
 		&mut TrackballController,
 	)>,
 	mut trackball_events: MessageWriter<TrackballMessage>,
+	#[cfg(feature = "bevy_picking")] hover_map: Option<Res<HoverMap>>,
 ) {
 	if viewport.was_stolen() {
 		touch_events.clear();
@@ -81,6 +201,52 @@ pub fn trackball_controller(
 		mouse_events.clear();
 		wheel_events.clear();
 	}
+	let t = time.delta_secs();
+	let is_changed = TrackballViewport::focus(
+		&mut viewport,
+		&key_input,
+		&mouse_input,
+		&mut touch_events_clone,
+		&wheel_events,
+		&primary_windows,
+		&secondary_windows,
+		&cameras,
+	);
+	for (group, _camera, _target, trackball, mut controller) in &mut cameras {
+		if viewport.entity() == Some(group) {
+			continue;
+		}
+		let inertia = controller.input.inertia;
+		controller
+			.inertia
+			.update(group, &mut trackball_events, inertia, t);
+		let Some(pad) = controller
+			.input
+			.gamepad
+			.and_then(|entity| gamepads.get(entity).ok())
+		else {
+			continue;
+		};
+		controller.tilt = 0.0;
+		let mut actions = std::mem::take(&mut controller.actions);
+		actions.update(&controller.input.bindings, None, None, Some(pad));
+		let zat = trackball.frame.distance();
+		let v = controller.input.velocity.to_linear(zat).into_inner();
+		let w = controller.input.velocity.to_angular(zat).into_inner();
+		gamepad(
+			group,
+			&mut trackball_events,
+			trackball,
+			&mut controller,
+			&actions,
+			pad,
+			zat,
+			w,
+			v,
+			t,
+		);
+		controller.actions = actions;
+	}
 	let Some((
 		is_changed,
 		window_id,
@@ -92,10 +258,7 @@ pub fn trackball_controller(
 		mut controller,
 	)) = TrackballViewport::select(
 		&mut viewport,
-		&key_input,
-		&mouse_input,
-		&mut touch_events_clone,
-		&wheel_events,
+		is_changed,
 		&mut primary_windows,
 		&mut secondary_windows,
 		&mut cameras,
@@ -110,10 +273,20 @@ pub fn trackball_controller(
 		return;
 	};
 	if is_changed {
+		controller.actions.discard();
+		controller.inertia.discard();
 		controller.first_count = 0;
 		controller.first.discard();
 		controller.orbit.discard();
 		controller.slide.discard();
+		controller.zoom.discard();
+		controller.pivot = Point3::origin();
+		controller.fly = false;
+		controller.fall = 0.0;
+		controller.grounded = false;
+		controller.click.discard();
+		controller.tap.discard();
+		controller.snap.update(false);
 		controller.touch.discard(None);
 		controller.touch.discard(None);
 		commands
@@ -122,37 +295,79 @@ pub fn trackball_controller(
 		cursor_options.grab_mode = CursorGrabMode::None;
 		cursor_options.visible = true;
 	}
+	controller.tilt = 0.0;
 	let zat = trackball.frame.distance();
 	let (_max, upp) = trackball.scope.fov().max_and_upp(zat, &max.into());
 	let v = controller.input.velocity.to_linear(zat).into_inner();
 	let w = controller.input.velocity.to_angular(zat).into_inner();
-	let t = time.delta_secs();
+	let pad = controller.input.gamepad.map_or_else(
+		|| gamepads.iter().next(),
+		|entity| gamepads.get(entity).ok(),
+	);
+	let mut actions = std::mem::take(&mut controller.actions);
+	actions.update(
+		&controller.input.bindings,
+		Some(&key_input),
+		Some(&mouse_input),
+		pad,
+	);
+	#[cfg(feature = "bevy_picking")]
+	let pick = |id: Option<u64>| {
+		picked(
+			hover_map.as_deref(),
+			id.map_or(PointerId::Mouse, PointerId::Touch),
+			group,
+		)
+	};
+	#[cfg(not(feature = "bevy_picking"))]
+	let pick = |_id: Option<u64>| None;
+	let double_click = controller.input.double_click;
+	if let Some(vec) = controller.click.update(double_click, t) {
+		trackball_events.write(TrackballMessage::slide(group, vec));
+	}
+	if let Some(vec) = controller.tap.update(double_click, t) {
+		trackball_events.write(TrackballMessage::slide(group, vec));
+	}
+	if actions.any_just_pressed() || !wheel_events.is_empty() {
+		controller.inertia.discard();
+	}
+	controller
+		.snap
+		.update(actions.pressed(TrackballAction::Snap));
 	key(
 		group,
 		&mut trackball_events,
 		trackball,
 		&mut controller,
-		&mut cursor_options,
-		&key_input,
-		&mouse_input,
+		&actions,
 		zat,
 		w,
 		v,
 		t,
 	);
+	walk(
+		group,
+		&mut trackball_events,
+		trackball,
+		&mut controller,
+		&actions,
+		v,
+		t,
+	);
 	mouse(
 		&mut commands,
 		group,
 		&mut trackball_events,
 		trackball,
 		&mut controller,
+		&actions,
 		window_id,
 		&window,
 		&mut cursor_options,
-		&mouse_input,
 		delta_events,
 		mouse_events,
 		wheel_events,
+		pick(None),
 		zat,
 		upp,
 		min,
@@ -165,8 +380,40 @@ pub fn trackball_controller(
 		trackball,
 		&mut controller,
 		touch_events,
+		pick,
 		upp,
 		min,
 		max,
 	);
+	if let Some(pad) = pad {
+		gamepad(
+			group,
+			&mut trackball_events,
+			trackball,
+			&mut controller,
+			&actions,
+			pad,
+			zat,
+			w,
+			v,
+			t,
+		);
+	}
+	let inertia = controller.input.inertia;
+	controller
+		.inertia
+		.update(group, &mut trackball_events, inertia, t);
+	controller.actions = actions;
+}
+
+/// Picks nearest surface point in world space hit by `pointer` of `camera`.
+#[cfg(feature = "bevy_picking")]
+fn picked(hover_map: Option<&HoverMap>, pointer: PointerId, camera: Entity) -> Option<Vec3> {
+	hover_map?
+		.get(&pointer)?
+		.values()
+		.filter(|hit| hit.camera == camera)
+		.filter_map(|hit| hit.position.map(|position| (hit.depth, position)))
+		.min_by(|(a, _), (b, _)| a.total_cmp(b))
+		.map(|(_depth, position)| position)
 }
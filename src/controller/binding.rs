@@ -0,0 +1,493 @@
+use std::{
+	collections::{HashMap, HashSet},
+	ops::BitOr,
+};
+
+use bevy::prelude::*;
+
+/// Trackball action bound to inputs via [`TrackballBindings`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum TrackballAction {
+	/// Resets frame. Default is [`KeyCode::Enter`].
+	Reset,
+	/// Toggles projection mode. Default is [`KeyCode::KeyP`].
+	///
+	/// Ignored in [`TrackballMode::Map`] which keeps the projection mode orthographic.
+	///
+	/// [`TrackballMode::Map`]: crate::TrackballMode::Map
+	Ortho,
+	/// Toggles gamer mapping of slide operations. Default is [`KeyCode::KeyM`].
+	///
+	/// Applies [`TrackballInput::map_wasd`] or, in fly and walk modes, [`TrackballInput::map_esdf`]
+	/// and restores the previous slide bindings when toggled again.
+	///
+	/// [`TrackballInput::map_wasd`]: crate::TrackballInput::map_wasd
+	/// [`TrackballInput::map_esdf`]: crate::TrackballInput::map_esdf
+	Gamer,
+
+	/// Looks around by mouse, single-finger touch, or gamepad stick while active.
+	///
+	/// Default is [`MouseButton::Middle`], [`KeyCode::ShiftLeft`], and
+	/// [`GamepadButton::LeftTrigger`]. The mouse cursor is grabbed unless activated by gamepad
+	/// buttons only.
+	First,
+	/// Looks left. Default is [`KeyCode::ArrowLeft`].
+	FirstLeft,
+	/// Looks right. Default is [`KeyCode::ArrowRight`].
+	FirstRight,
+	/// Looks up. Default is [`KeyCode::ArrowUp`].
+	FirstUp,
+	/// Looks down. Default is [`KeyCode::ArrowDown`].
+	FirstDown,
+
+	/// Orbits by mouse while active. Default is [`MouseButton::Left`].
+	Orbit,
+	/// Screws/rolls left. Default is [`KeyCode::KeyU`].
+	ScrewLeft,
+	/// Screws/rolls right. Default is [`KeyCode::KeyO`].
+	ScrewRight,
+	/// Orbits left. Default is [`KeyCode::KeyJ`].
+	OrbitLeft,
+	/// Orbits right. Default is [`KeyCode::KeyL`].
+	OrbitRight,
+	/// Orbits up. Default is [`KeyCode::KeyI`].
+	OrbitUp,
+	/// Orbits down. Default is [`KeyCode::KeyK`].
+	OrbitDown,
+
+	/// Slides by mouse while active. Default is [`MouseButton::Right`].
+	Slide,
+	/// Slides left. Default is [`KeyCode::KeyS`].
+	SlideLeft,
+	/// Slides right. Default is [`KeyCode::KeyF`].
+	SlideRight,
+	/// Slides up. Default is [`KeyCode::KeyE`].
+	SlideUp,
+	/// Slides down. Default is [`KeyCode::KeyD`].
+	SlideDown,
+	/// Slides far. Default is [`KeyCode::KeyG`].
+	SlideFar,
+	/// Slides near. Default is [`KeyCode::KeyV`].
+	SlideNear,
+	/// Speeds up sliding by keys while active in fly mode. Default is unbound.
+	///
+	/// See [`TrackballMode::Fly`].
+	///
+	/// [`TrackballMode::Fly`]: crate::TrackballMode::Fly
+	Boost,
+
+	/// Scales/zooms by vertical mouse drag while active. Default is unbound.
+	Scale,
+	/// Scales/zooms in. Default is [`KeyCode::KeyH`].
+	ScaleIn,
+	/// Scales/zooms out. Default is [`KeyCode::KeyN`].
+	ScaleOut,
+
+	/// Flies target to picked surface point when double clicked. Default is [`MouseButton::Left`].
+	///
+	/// See [`TrackballInput::double_click`].
+	///
+	/// [`TrackballInput::double_click`]: crate::TrackballInput::double_click
+	Target,
+
+	/// Snaps orbit, screw/roll, and look around to angle steps while active. Default is
+	/// [`KeyCode::AltLeft`].
+	///
+	/// See [`TrackballInput::orbit_snap`], [`TrackballInput::screw_snap`], and
+	/// [`TrackballInput::first_snap`].
+	///
+	/// [`TrackballInput::orbit_snap`]: crate::TrackballInput::orbit_snap
+	/// [`TrackballInput::screw_snap`]: crate::TrackballInput::screw_snap
+	/// [`TrackballInput::first_snap`]: crate::TrackballInput::first_snap
+	Snap,
+
+	/// Animates to [`StandardView::Front`]. Default is [`KeyCode::Numpad1`].
+	///
+	/// [`StandardView::Front`]: crate::StandardView::Front
+	ViewFront,
+	/// Animates to [`StandardView::Back`]. Default is `Ctrl` + [`KeyCode::Numpad1`].
+	///
+	/// [`StandardView::Back`]: crate::StandardView::Back
+	ViewBack,
+	/// Animates to [`StandardView::Left`]. Default is `Ctrl` + [`KeyCode::Numpad3`].
+	///
+	/// [`StandardView::Left`]: crate::StandardView::Left
+	ViewLeft,
+	/// Animates to [`StandardView::Right`]. Default is [`KeyCode::Numpad3`].
+	///
+	/// [`StandardView::Right`]: crate::StandardView::Right
+	ViewRight,
+	/// Animates to [`StandardView::Top`]. Default is [`KeyCode::Numpad7`].
+	///
+	/// [`StandardView::Top`]: crate::StandardView::Top
+	ViewTop,
+	/// Animates to [`StandardView::Bottom`]. Default is `Ctrl` + [`KeyCode::Numpad7`].
+	///
+	/// [`StandardView::Bottom`]: crate::StandardView::Bottom
+	ViewBottom,
+	/// Animates to [`StandardView::Isometric`]. Default is [`KeyCode::Numpad0`].
+	///
+	/// [`StandardView::Isometric`]: crate::StandardView::Isometric
+	ViewIsometric,
+
+	/// Stores camera state in bookmark slot from `1` to `9`. Default is `Ctrl` +
+	/// [`KeyCode::Digit1`] to [`KeyCode::Digit9`].
+	///
+	/// See [`TrackballBookmarks`].
+	///
+	/// [`TrackballBookmarks`]: crate::TrackballBookmarks
+	StoreBookmark(u8),
+	/// Recalls camera state from bookmark slot from `1` to `9`. Default is [`KeyCode::Digit1`] to
+	/// [`KeyCode::Digit9`].
+	///
+	/// See [`TrackballBookmarks`].
+	///
+	/// [`TrackballBookmarks`]: crate::TrackballBookmarks
+	RecallBookmark(u8),
+}
+
+/// Button of [`TrackballBinding`].
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TrackballButton {
+	/// Keyboard key.
+	Key(KeyCode),
+	/// Mouse button.
+	Mouse(MouseButton),
+	/// Gamepad button of the gamepad driving the controller, see [`TrackballInput::gamepad`].
+	///
+	/// [`TrackballInput::gamepad`]: crate::TrackballInput::gamepad
+	Gamepad(GamepadButton),
+}
+
+/// Set of modifier keys of [`TrackballBinding`], each matching its left or right key.
+///
+/// Modifiers are combined with the `|` operator (e.g., `TrackballModifiers::CONTROL |
+/// TrackballModifiers::SHIFT`).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct TrackballModifiers(u8);
+
+impl TrackballModifiers {
+	/// No modifier.
+	pub const NONE: Self = Self(0);
+	/// [`KeyCode::ShiftLeft`] or [`KeyCode::ShiftRight`].
+	pub const SHIFT: Self = Self(1 << 0);
+	/// [`KeyCode::ControlLeft`] or [`KeyCode::ControlRight`].
+	pub const CONTROL: Self = Self(1 << 1);
+	/// [`KeyCode::AltLeft`] or [`KeyCode::AltRight`].
+	pub const ALT: Self = Self(1 << 2);
+	/// [`KeyCode::SuperLeft`] or [`KeyCode::SuperRight`].
+	pub const SUPER: Self = Self(1 << 3);
+
+	/// Whether all modifiers of `other` are contained in `self`.
+	#[must_use]
+	pub const fn contains(self, other: Self) -> bool {
+		self.0 & other.0 == other.0
+	}
+	/// Number of modifiers.
+	#[must_use]
+	pub const fn len(self) -> u32 {
+		self.0.count_ones()
+	}
+	/// Whether there are no modifiers.
+	#[must_use]
+	pub const fn is_empty(self) -> bool {
+		self.0 == 0
+	}
+	/// Currently pressed modifiers.
+	#[must_use]
+	pub fn pressed(key_input: &ButtonInput<KeyCode>) -> Self {
+		[
+			(Self::SHIFT, [KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+			(Self::CONTROL, [KeyCode::ControlLeft, KeyCode::ControlRight]),
+			(Self::ALT, [KeyCode::AltLeft, KeyCode::AltRight]),
+			(Self::SUPER, [KeyCode::SuperLeft, KeyCode::SuperRight]),
+		]
+		.into_iter()
+		.filter(|(_modifier, keys)| key_input.any_pressed(*keys))
+		.fold(Self::NONE, |modifiers, (modifier, _keys)| {
+			modifiers | modifier
+		})
+	}
+}
+
+impl BitOr for TrackballModifiers {
+	type Output = Self;
+
+	fn bitor(self, other: Self) -> Self {
+		Self(self.0 | other.0)
+	}
+}
+
+/// Input binding of [`TrackballAction`] as button optionally chorded with modifiers.
+///
+/// Converts from [`KeyCode`], [`MouseButton`], and [`GamepadButton`] without modifiers. Whenever
+/// multiple bindings of the same button are satisfied, only the one with the most modifiers is
+/// active (e.g., `Ctrl` + [`MouseButton::Left`] slides instead of orbiting when bound to
+/// [`TrackballAction::Slide`] while [`MouseButton::Left`] is bound to [`TrackballAction::Orbit`]).
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct TrackballBinding {
+	/// Modifiers to be pressed in addition to [`Self::button`].
+	pub modifiers: TrackballModifiers,
+	/// Button to be pressed.
+	pub button: TrackballButton,
+}
+
+impl TrackballBinding {
+	/// Chords `button` with `modifiers`.
+	#[must_use]
+	pub fn chord(modifiers: TrackballModifiers, button: impl Into<TrackballButton>) -> Self {
+		Self {
+			modifiers,
+			button: button.into(),
+		}
+	}
+}
+
+impl From<KeyCode> for TrackballButton {
+	fn from(key: KeyCode) -> Self {
+		Self::Key(key)
+	}
+}
+
+impl From<MouseButton> for TrackballButton {
+	fn from(button: MouseButton) -> Self {
+		Self::Mouse(button)
+	}
+}
+
+impl From<GamepadButton> for TrackballButton {
+	fn from(button: GamepadButton) -> Self {
+		Self::Gamepad(button)
+	}
+}
+
+impl<T: Into<TrackballButton>> From<T> for TrackballBinding {
+	fn from(button: T) -> Self {
+		Self::chord(TrackballModifiers::NONE, button)
+	}
+}
+
+/// [`TrackballInput`] setting mapping each [`TrackballAction`] to a list of [`TrackballBinding`].
+///
+/// An action is active as long as any of its bindings is pressed. Actions without bindings are
+/// disabled.
+///
+/// [`TrackballInput`]: crate::TrackballInput
+#[derive(Debug, Clone)]
+pub struct TrackballBindings(HashMap<TrackballAction, Vec<TrackballBinding>>);
+
+impl TrackballBindings {
+	/// Bindings without any bound action.
+	#[must_use]
+	pub fn empty() -> Self {
+		Self(HashMap::default())
+	}
+	/// Bindings of `action`.
+	#[must_use]
+	pub fn get(&self, action: TrackballAction) -> &[TrackballBinding] {
+		self.0.get(&action).map_or(&[], Vec::as_slice)
+	}
+	/// Whether `action` is bound to `binding`.
+	#[must_use]
+	pub fn contains(&self, action: TrackballAction, binding: impl Into<TrackballBinding>) -> bool {
+		self.get(action).contains(&binding.into())
+	}
+	/// Replaces bindings of `action`.
+	pub fn set<B: Into<TrackballBinding>>(
+		&mut self,
+		action: TrackballAction,
+		bindings: impl IntoIterator<Item = B>,
+	) {
+		self.0
+			.insert(action, bindings.into_iter().map(Into::into).collect());
+	}
+	/// Adds `binding` to bindings of `action`.
+	pub fn bind(&mut self, action: TrackballAction, binding: impl Into<TrackballBinding>) {
+		let binding = binding.into();
+		let bindings = self.0.entry(action).or_default();
+		if !bindings.contains(&binding) {
+			bindings.push(binding);
+		}
+	}
+	/// Removes `binding` from bindings of all actions.
+	pub fn unbind(&mut self, binding: impl Into<TrackballBinding>) {
+		let binding = binding.into();
+		for bindings in self.0.values_mut() {
+			bindings.retain(|other| *other != binding);
+		}
+	}
+	/// Removes all bindings of `action`.
+	pub fn clear(&mut self, action: TrackballAction) {
+		self.0.remove(&action);
+	}
+	/// Retains only the bindings specified by the predicate.
+	pub fn retain(&mut self, mut f: impl FnMut(TrackballAction, &TrackballBinding) -> bool) {
+		for (&action, bindings) in &mut self.0 {
+			bindings.retain(|binding| f(action, binding));
+		}
+	}
+	/// Iterates actions with their bindings in arbitrary order.
+	pub fn iter(&self) -> impl Iterator<Item = (TrackballAction, &[TrackballBinding])> {
+		self.0
+			.iter()
+			.map(|(&action, bindings)| (action, bindings.as_slice()))
+	}
+}
+
+impl Default for TrackballBindings {
+	/// Default mapping, see each [`TrackballAction`].
+	fn default() -> Self {
+		let mut bindings = Self::empty();
+		bindings.set(TrackballAction::Reset, [KeyCode::Enter]);
+		bindings.set(TrackballAction::Ortho, [KeyCode::KeyP]);
+		bindings.set(TrackballAction::Gamer, [KeyCode::KeyM]);
+
+		bindings.set(TrackballAction::First, [MouseButton::Middle]);
+		bindings.bind(TrackballAction::First, KeyCode::ShiftLeft);
+		bindings.bind(TrackballAction::First, GamepadButton::LeftTrigger);
+		bindings.set(TrackballAction::FirstLeft, [KeyCode::ArrowLeft]);
+		bindings.set(TrackballAction::FirstRight, [KeyCode::ArrowRight]);
+		bindings.set(TrackballAction::FirstUp, [KeyCode::ArrowUp]);
+		bindings.set(TrackballAction::FirstDown, [KeyCode::ArrowDown]);
+
+		bindings.set(TrackballAction::Orbit, [MouseButton::Left]);
+		bindings.set(TrackballAction::ScrewLeft, [KeyCode::KeyU]);
+		bindings.set(TrackballAction::ScrewRight, [KeyCode::KeyO]);
+		bindings.set(TrackballAction::OrbitLeft, [KeyCode::KeyJ]);
+		bindings.set(TrackballAction::OrbitRight, [KeyCode::KeyL]);
+		bindings.set(TrackballAction::OrbitUp, [KeyCode::KeyI]);
+		bindings.set(TrackballAction::OrbitDown, [KeyCode::KeyK]);
+
+		bindings.set(TrackballAction::Slide, [MouseButton::Right]);
+		bindings.set(TrackballAction::SlideLeft, [KeyCode::KeyS]);
+		bindings.set(TrackballAction::SlideRight, [KeyCode::KeyF]);
+		bindings.set(TrackballAction::SlideUp, [KeyCode::KeyE]);
+		bindings.set(TrackballAction::SlideDown, [KeyCode::KeyD]);
+		bindings.set(TrackballAction::SlideFar, [KeyCode::KeyG]);
+		bindings.set(TrackballAction::SlideNear, [KeyCode::KeyV]);
+
+		bindings.set(TrackballAction::ScaleIn, [KeyCode::KeyH]);
+		bindings.set(TrackballAction::ScaleOut, [KeyCode::KeyN]);
+
+		bindings.set(TrackballAction::Target, [MouseButton::Left]);
+
+		bindings.set(TrackballAction::Snap, [KeyCode::AltLeft]);
+
+		let control = |key| TrackballBinding::chord(TrackballModifiers::CONTROL, key);
+		bindings.set(TrackballAction::ViewFront, [KeyCode::Numpad1]);
+		bindings.set(TrackballAction::ViewBack, [control(KeyCode::Numpad1)]);
+		bindings.set(TrackballAction::ViewLeft, [control(KeyCode::Numpad3)]);
+		bindings.set(TrackballAction::ViewRight, [KeyCode::Numpad3]);
+		bindings.set(TrackballAction::ViewTop, [KeyCode::Numpad7]);
+		bindings.set(TrackballAction::ViewBottom, [control(KeyCode::Numpad7)]);
+		bindings.set(TrackballAction::ViewIsometric, [KeyCode::Numpad0]);
+
+		for (slot, key) in (1..).zip([
+			KeyCode::Digit1,
+			KeyCode::Digit2,
+			KeyCode::Digit3,
+			KeyCode::Digit4,
+			KeyCode::Digit5,
+			KeyCode::Digit6,
+			KeyCode::Digit7,
+			KeyCode::Digit8,
+			KeyCode::Digit9,
+		]) {
+			bindings.set(TrackballAction::StoreBookmark(slot), [control(key)]);
+			bindings.set(TrackballAction::RecallBookmark(slot), [key]);
+		}
+		bindings
+	}
+}
+
+/// Active actions of current and previous frame resolved from [`TrackballBindings`].
+#[derive(Debug, Clone, Default)]
+pub struct Actions {
+	pressed: HashSet<TrackballAction>,
+	old_pressed: HashSet<TrackballAction>,
+	/// Active actions of current frame whose active bindings are all gamepad buttons.
+	gamepad: HashSet<TrackballAction>,
+}
+
+impl Actions {
+	/// Resolves active actions of current frame.
+	///
+	/// Inputs which are `None` are considered released.
+	pub fn update(
+		&mut self,
+		bindings: &TrackballBindings,
+		key_input: Option<&ButtonInput<KeyCode>>,
+		mouse_input: Option<&ButtonInput<MouseButton>>,
+		gamepad: Option<&Gamepad>,
+	) {
+		let modifiers = key_input.map_or(TrackballModifiers::NONE, TrackballModifiers::pressed);
+		let pressed = |button: TrackballButton| match button {
+			TrackballButton::Key(key) => key_input.is_some_and(|input| input.pressed(key)),
+			TrackballButton::Mouse(button) => {
+				mouse_input.is_some_and(|input| input.pressed(button))
+			}
+			TrackballButton::Gamepad(button) => gamepad.is_some_and(|input| input.pressed(button)),
+		};
+		let candidates = bindings
+			.iter()
+			.flat_map(|(action, bindings)| bindings.iter().map(move |binding| (action, binding)))
+			.filter(|(_action, binding)| {
+				modifiers.contains(binding.modifiers) && pressed(binding.button)
+			})
+			.collect::<Vec<_>>();
+		let active = candidates
+			.iter()
+			.filter(|(_action, binding)| {
+				!candidates.iter().any(|(_other_action, other)| {
+					other.button == binding.button
+						&& other.modifiers.contains(binding.modifiers)
+						&& other.modifiers.len() > binding.modifiers.len()
+				})
+			})
+			.collect::<Vec<_>>();
+		self.old_pressed = std::mem::take(&mut self.pressed);
+		self.pressed = active.iter().map(|&&(action, _binding)| action).collect();
+		self.gamepad = self
+			.pressed
+			.iter()
+			.copied()
+			.filter(|&action| {
+				active
+					.iter()
+					.filter(|(other, _binding)| *other == action)
+					.all(|(_action, binding)| matches!(binding.button, TrackballButton::Gamepad(_)))
+			})
+			.collect();
+	}
+	/// Discards active actions of current and previous frame.
+	pub fn discard(&mut self) {
+		self.pressed.clear();
+		self.old_pressed.clear();
+		self.gamepad.clear();
+	}
+	/// Whether `action` is active.
+	pub fn pressed(&self, action: TrackballAction) -> bool {
+		self.pressed.contains(&action)
+	}
+	/// Whether `action` is active by gamepad buttons only.
+	pub fn gamepad(&self, action: TrackballAction) -> bool {
+		self.gamepad.contains(&action)
+	}
+	/// Whether any action is active.
+	pub fn any_pressed(&self) -> bool {
+		!self.pressed.is_empty()
+	}
+	/// Whether any action has just become active.
+	pub fn any_just_pressed(&self) -> bool {
+		self.pressed.difference(&self.old_pressed).next().is_some()
+	}
+	/// Whether `action` has just become active.
+	pub fn just_pressed(&self, action: TrackballAction) -> bool {
+		self.pressed.contains(&action) && !self.old_pressed.contains(&action)
+	}
+	/// Whether `action` has just become inactive.
+	pub fn just_released(&self, action: TrackballAction) -> bool {
+		!self.pressed.contains(&action) && self.old_pressed.contains(&action)
+	}
+}
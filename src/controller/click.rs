@@ -0,0 +1,86 @@
+use trackball::nalgebra::{Point2, Vector3};
+
+/// Maximum distance in pixels between clicks of a double click.
+const SLOP: f32 = 8.0;
+
+/// Double-click or double-tap recognition.
+///
+/// Only clicks hitting a surface point are registered. While a double click is pending, focus
+/// operations are deferred and orbit operations within [`SLOP`] are suppressed, so the second click
+/// picks under an unmoved view.
+#[derive(Debug, Clone, Default)]
+pub struct DoubleClick {
+	/// Elapsed time in seconds since and position of previous click.
+	last: Option<(f32, Point2<f32>)>,
+	/// Focus slide deferred until previous click is forgotten.
+	focus: Option<Vector3<f32>>,
+	/// Whether completed double click is still held.
+	held: bool,
+}
+
+impl DoubleClick {
+	/// Ages previous click by `t` seconds forgetting it after `interval` in milliseconds.
+	///
+	/// Returns deferred focus slide once previous click is forgotten.
+	pub fn update(&mut self, interval: f32, t: f32) -> Option<Vector3<f32>> {
+		if let Some((elapsed, _pos)) = &mut self.last {
+			*elapsed += t;
+			if *elapsed > interval * 1e-3 {
+				self.last = None;
+			}
+		}
+		self.last.is_none().then(|| self.focus.take()).flatten()
+	}
+	/// Registers click at `pos` and returns whether it completes a double click.
+	pub fn compute(&mut self, pos: Point2<f32>) -> bool {
+		if self
+			.last
+			.take()
+			.is_some_and(|(_elapsed, last)| (pos - last).norm() <= SLOP)
+		{
+			self.focus = None;
+			self.held = true;
+			true
+		} else {
+			self.last = Some((0.0, pos));
+			false
+		}
+	}
+	/// Forgets previous click as the current click has missed any surface point.
+	///
+	/// Returns deferred focus slide, so it is applied before the focus slide of the current click.
+	pub const fn miss(&mut self) -> Option<Vector3<f32>> {
+		self.last = None;
+		self.held = false;
+		self.focus.take()
+	}
+	/// Whether double click is pending at `pos`, so orbit operations are suppressed.
+	pub fn pending(&self, pos: Point2<f32>) -> bool {
+		self.held
+			|| self
+				.last
+				.is_some_and(|(_elapsed, last)| (pos - last).norm() <= SLOP)
+	}
+	/// Defers focus slide `vec` while double click is pending.
+	///
+	/// Returns `vec` if no double click is pending or `None` if deferred or discarded as completed
+	/// double click is still held.
+	pub const fn defer(&mut self, vec: Vector3<f32>) -> Option<Vector3<f32>> {
+		if self.held {
+			None
+		} else if self.last.is_some() {
+			self.focus = Some(vec);
+			None
+		} else {
+			Some(vec)
+		}
+	}
+	/// Releases completed double click.
+	pub const fn release(&mut self) {
+		self.held = false;
+	}
+	/// Forgets previous click and deferred focus slide.
+	pub fn discard(&mut self) {
+		*self = Self::default();
+	}
+}
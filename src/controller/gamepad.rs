@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use trackball::nalgebra::{Point3, Unit, UnitQuaternion, Vector3};
+
+use super::{Actions, TrackballAction, TrackballCamera, TrackballController, TrackballMessage};
+
+#[allow(clippy::too_many_arguments)]
+pub fn gamepad(
+	group: Entity,
+	trackball_events: &mut MessageWriter<TrackballMessage>,
+	trackball: &TrackballCamera,
+	controller: &mut TrackballController,
+	actions: &Actions,
+	gamepad: &Gamepad,
+	zat: f32,
+	w: f32,
+	v: f32,
+	t: f32,
+) {
+	let dead_zone = controller
+		.input
+		.gamepad_dead_zone
+		.clamp(0.0, 1.0 - f32::EPSILON);
+	let stick = |axes: Option<[GamepadAxis; 2]>| {
+		axes.map_or(Vec2::ZERO, |[x, y]| {
+			let vec = Vec2::new(
+				gamepad.get(x).unwrap_or_default(),
+				gamepad.get(y).unwrap_or_default(),
+			);
+			let len = vec.length();
+			if len > dead_zone {
+				vec * ((len.min(1.0) - dead_zone) / (1.0 - dead_zone) / len)
+			} else {
+				Vec2::ZERO
+			}
+		})
+	};
+	let trigger = |button: Option<GamepadButton>| {
+		button.map_or(0.0, |button| {
+			let value = gamepad.get(button).unwrap_or_default().clamp(0.0, 1.0);
+			((value - dead_zone) / (1.0 - dead_zone)).max(0.0)
+		})
+	};
+	let orbits = controller.mode.orbits();
+	let first = actions.pressed(TrackballAction::First) && orbits;
+	if first && !controller.first.enabled() {
+		controller.first.capture(trackball.frame.yaw_axis());
+	}
+	if actions.just_released(TrackballAction::First) {
+		controller.first.discard();
+	}
+	let vec = stick(controller.input.orbit_gamepad_axes);
+	if vec != Vec2::ZERO && orbits {
+		if first {
+			if let Some(&yaw_axis) = controller.first.yaw_axis() {
+				let ang = vec * w * t * controller.input.first_gamepad_transmission;
+				trackball_events.write(TrackballMessage::first(group, ang.y, -ang.x, yaw_axis));
+			}
+		} else {
+			let w = w * controller.input.orbit_gamepad_transmission;
+			let axis = Unit::new_normalize(Vector3::new(-vec.y, vec.x, 0.0));
+			let rot = UnitQuaternion::from_axis_angle(&axis, vec.length() * w * t);
+			let (yaw, pitch) = (vec.x * w * t, -vec.y * w * t);
+			let rot = (controller.mode)
+				.orbit(&trackball.frame, &mut controller.tilt, yaw, pitch)
+				.unwrap_or(rot);
+			trackball_events.write(TrackballMessage::orbit(group, rot, Point3::origin()));
+		}
+	}
+	let vec = stick(controller.input.slide_gamepad_axes);
+	if vec != Vec2::ZERO {
+		let v = v * controller.input.slide_gamepad_transmission;
+		let vec = vec.extend(0.0) * v * t;
+		trackball_events.write(TrackballMessage::slide(group, vec.into()));
+	}
+	let num = trigger(controller.input.scale_in_gamepad_button)
+		- trigger(controller.input.scale_out_gamepad_button);
+	if num.abs() > f32::EPSILON {
+		let v = v * controller.input.scale_gamepad_transmission;
+		controller.scale.set_denominator(zat);
+		trackball_events.write(TrackballMessage::scale(
+			group,
+			controller.scale.compute(num * v * t),
+			Point3::origin(),
+		));
+	}
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use trackball::nalgebra::{Point3, UnitQuaternion, Vector3};
+
+use super::TrackballMessage;
+
+/// Half-life of smoothing the tracked velocity in seconds.
+const SMOOTHING: f32 = 0.02;
+/// Velocity below which coasting stops.
+const EPSILON: f32 = 1e-3;
+
+/// Kinetic inertia tracking release velocity of drag operations and coasting after release.
+#[derive(Debug, Clone, Default)]
+pub struct Inertia {
+	/// Angular velocity of orbit as scaled axis in camera space.
+	orbit: Vector3<f32>,
+	/// Linear velocity of slide in camera space.
+	slide: Vector3<f32>,
+	/// Logarithmic velocity of scale ratio.
+	scale: f32,
+	/// Orbit center in camera space.
+	pivot: Point3<f32>,
+	/// Accumulated deltas of current frame.
+	delta: (Vector3<f32>, Vector3<f32>, f32),
+	/// Whether coasting after release.
+	coast: bool,
+}
+
+impl Inertia {
+	/// Tracks orbit delta of drag operation around `pos` in camera space.
+	pub fn orbit(&mut self, rot: &UnitQuaternion<f32>, pos: &Point3<f32>) {
+		self.delta.0 += rot.scaled_axis();
+		self.pivot = *pos;
+	}
+	/// Tracks slide delta of drag operation.
+	pub fn slide(&mut self, vec: &Vector3<f32>) {
+		self.delta.1 += vec;
+	}
+	/// Tracks scale delta of drag operation.
+	pub fn scale(&mut self, rat: f32) {
+		if rat > 0.0 {
+			self.delta.2 += rat.ln();
+		}
+	}
+	/// Starts coasting with tracked velocity on release of drag operation.
+	pub const fn release(&mut self) {
+		self.coast = true;
+	}
+	/// Whether coasting after release.
+	pub const fn is_coasting(&self) -> bool {
+		self.coast
+	}
+	/// Stops coasting and forgets tracked velocity.
+	pub fn discard(&mut self) {
+		*self = Self::default();
+	}
+	/// Updates tracked velocity or emits decaying deltas while coasting.
+	///
+	/// The `half_life` of friction is in milliseconds where zero disables inertia.
+	pub fn update(
+		&mut self,
+		group: Entity,
+		trackball_events: &mut MessageWriter<TrackballMessage>,
+		half_life: f32,
+		t: f32,
+	) {
+		if half_life <= 0.0 || t <= 0.0 {
+			self.discard();
+			return;
+		}
+		if self.coast {
+			if self.orbit.norm() >= EPSILON {
+				let rot = UnitQuaternion::from_scaled_axis(self.orbit * t);
+				trackball_events.write(TrackballMessage::orbit(group, rot, self.pivot));
+			}
+			if self.slide.norm() >= EPSILON {
+				trackball_events.write(TrackballMessage::slide(group, self.slide * t));
+			}
+			if self.scale.abs() >= EPSILON {
+				let rat = (self.scale * t).exp();
+				trackball_events.write(TrackballMessage::scale(group, rat, Point3::origin()));
+			}
+			let friction = 0.5f32.powf(t / (half_life * 1e-3).min(1.0));
+			self.orbit *= friction;
+			self.slide *= friction;
+			self.scale *= friction;
+			if self.orbit.norm() < EPSILON
+				&& self.slide.norm() < EPSILON
+				&& self.scale.abs() < EPSILON
+			{
+				self.discard();
+			}
+		} else {
+			let (orbit, slide, scale) = std::mem::take(&mut self.delta);
+			let blend = 1.0 - 0.5f32.powf(t / SMOOTHING);
+			self.orbit = self.orbit.lerp(&(orbit / t), blend);
+			self.slide = self.slide.lerp(&(slide / t), blend);
+			self.scale += (scale / t - self.scale) * blend;
+		}
+	}
+}
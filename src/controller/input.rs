@@ -1,8 +1,15 @@
+use std::f32::consts::PI;
+
 use bevy::prelude::*;
 use trackball::Fixed;
 
+use super::{
+	TrackballAction, TrackballBinding, TrackballBindings, TrackballButton, TrackballModifiers,
+};
+
 /// Trackball controller input mappings and settings.
 #[derive(Component, Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct TrackballInput {
 	/// Trackball velocity for time-based input like pressed keys.
 	pub velocity: TrackballVelocity,
@@ -44,6 +51,10 @@ pub struct TrackballInput {
 	///
 	/// Values unequal the default deviate from coherence as in movement equals ratio times input.
 	pub slide_mouse_transmission: f32,
+	/// Transmission ratio of movement to input for scale by mouse drag. Default is `1.0`.
+	///
+	/// Values unequal the default deviate from coherence as in movement equals ratio times input.
+	pub scale_mouse_transmission: f32,
 	/// Transmission ratio of movement to input for scale by wheel. Default is `1.0`.
 	///
 	/// Values unequal the default deviate from coherence as in movement equals ratio times input.
@@ -70,69 +81,117 @@ pub struct TrackballInput {
 	/// Values unequal the default deviate from coherence as in movement equals ratio times input.
 	pub scale_touch_transmission: f32,
 
-	/// Enables focus operation. Default is `true`.
+	/// Transmission ratio of movement to input for look around by gamepad. Default is `1.0`.
 	///
-	/// Whether to slide towards mouse or single-finger touch position when [`Self::orbit_button`]
-	/// is just pressed and released again or single-finger gesture is just started and ended again.
-	/// Moving the cursor/finger slightly between pressed/started and released/ended events discards
-	/// the focus operation in favor of the orbit operation.
-	pub focus: bool,
-
-	/// Key used to toggle `esdf`/`wasd` mapping. Default is [`KeyCode::KeyM`].
-	pub gamer_key: Option<KeyCode>,
-	/// Key used to toggle projection mode. Default is [`KeyCode::KeyP`].
-	pub ortho_key: Option<KeyCode>,
+	/// Values unequal the default deviate from coherence as in movement equals ratio times input.
+	pub first_gamepad_transmission: f32,
+	/// Transmission ratio of movement to input for orbit by gamepad. Default is `1.0`.
+	///
+	/// Values unequal the default deviate from coherence as in movement equals ratio times input.
+	pub orbit_gamepad_transmission: f32,
+	/// Transmission ratio of movement to input for slide by gamepad. Default is `1.0`.
+	///
+	/// Values unequal the default deviate from coherence as in movement equals ratio times input.
+	pub slide_gamepad_transmission: f32,
+	/// Transmission ratio of movement to input for scale by gamepad. Default is `1.0`.
+	///
+	/// Values unequal the default deviate from coherence as in movement equals ratio times input.
+	pub scale_gamepad_transmission: f32,
 
-	/// Key used to reset frame. Default is [`KeyCode::Enter`].
-	pub reset_key: Option<KeyCode>,
+	/// Inertia half-life from 0 (disabled) to 1000 (slow) milliseconds. Default is `0.0`.
+	///
+	/// Whenever a drag operation (i.e., orbit, slide, or scale by mouse or touch) is released, the
+	/// camera keeps moving with the release velocity decaying by fps-agnostic exponential friction
+	/// where the half-life is the time passed until halfway of the velocity is lost. Any new input
+	/// stops the movement.
+	pub inertia: f32,
 
-	/// Mouse button used to look around. Default is [`MouseButton::Middle`].
-	pub first_button: Option<MouseButton>,
-	/// Key used to look around with single-finger touch. Default is [`KeyCode::ShiftLeft`].
-	pub first_key: Option<KeyCode>,
-	/// Key used to look left. Default is [`KeyCode::ArrowLeft`].
-	pub first_left_key: Option<KeyCode>,
-	/// Key used to look right. Default is [`KeyCode::ArrowRight`].
-	pub first_right_key: Option<KeyCode>,
-	/// Key used to look up. Default is [`KeyCode::ArrowUp`].
-	pub first_up_key: Option<KeyCode>,
-	/// Key used to look down. Default is [`KeyCode::ArrowDown`].
-	pub first_down_key: Option<KeyCode>,
+	/// Snapping step of orbit in radians. Default is `PI / 12.0` (i.e., 15°).
+	///
+	/// While [`TrackballAction::Snap`] is active, orbiting by mouse, key, or touch accumulates the
+	/// raw rotation and only moves by whole steps of its angle. Non-positive values disable
+	/// snapping of orbit.
+	pub orbit_snap: f32,
+	/// Snapping step of screw/roll in radians. Default is `PI / 12.0` (i.e., 15°).
+	///
+	/// Like [`Self::orbit_snap`] but for screwing/rolling by key or two-finger touch.
+	pub screw_snap: f32,
+	/// Snapping step of look around in radians. Default is `PI / 12.0` (i.e., 15°).
+	///
+	/// Like [`Self::orbit_snap`] but for pitch and yaw angles of looking around by mouse, key, or
+	/// touch.
+	pub first_snap: f32,
 
-	/// Mouse button used to orbit camera. Default is [`MouseButton::Left`].
-	pub orbit_button: Option<MouseButton>,
-	/// Key used to screw/roll left. Default is [`KeyCode::KeyU`].
-	pub screw_left_key: Option<KeyCode>,
-	/// Key used to screw/roll right. Default is [`KeyCode::KeyO`].
-	pub screw_right_key: Option<KeyCode>,
-	/// Key used to orbit left. Default is [`KeyCode::KeyJ`].
-	pub orbit_left_key: Option<KeyCode>,
-	/// Key used to orbit right. Default is [`KeyCode::KeyL`].
-	pub orbit_right_key: Option<KeyCode>,
-	/// Key used to orbit up. Default is [`KeyCode::KeyI`].
-	pub orbit_up_key: Option<KeyCode>,
-	/// Key used to orbit down. Default is [`KeyCode::KeyK`].
-	pub orbit_down_key: Option<KeyCode>,
+	/// Enables focus operation. Default is `true`.
+	///
+	/// Whether to slide towards mouse or single-finger touch position when
+	/// [`TrackballAction::Orbit`] is just pressed and released again or single-finger gesture is
+	/// just started and ended again. Moving the cursor/finger slightly between pressed/started and
+	/// released/ended events discards the focus operation in favor of the orbit operation. While a
+	/// double click/tap is pending, see [`Self::double_click`], the focus operation is deferred
+	/// until the interval has passed and is discarded if the double click/tap completes.
+	pub focus: bool,
+	/// Enables orbiting around picked surface point. Default is `false`.
+	///
+	/// Whether to orbit around the surface point under the cursor instead of the target whenever
+	/// [`TrackballAction::Orbit`] is just pressed, so the picked point stays put. Falls back to the
+	/// target if nothing is hit. Requires the `bevy_picking` feature gate and a picking backend
+	/// reporting hit positions in world space (e.g., `MeshPickingPlugin`).
+	pub pick_orbit: bool,
+	/// Enables scaling around picked surface point. Default is `false`.
+	///
+	/// Whether to scale around the surface point under the cursor instead of the cursor position
+	/// projected onto the focus plane whenever the mouse wheel is scrolled, so zooming moves
+	/// straight towards the geometry under the cursor regardless of its depth. Falls back to the
+	/// focus plane if nothing is hit. Requires the `bevy_picking` feature gate and a picking
+	/// backend reporting hit positions in world space (e.g., `MeshPickingPlugin`).
+	pub pick_scale: bool,
+	/// Double-click/tap interval from 0 (disabled) to 1000 (slow) milliseconds. Default is `0.0`.
+	///
+	/// Double clicking [`TrackballAction::Target`] or double tapping with a single finger flies
+	/// the target to the surface point under the cursor/finger preserving the eye rotation, see
+	/// [`TrackballMessage::target`]. Requires the `bevy_picking` feature gate and a picking backend
+	/// reporting hit positions in world space (e.g., `MeshPickingPlugin`). Only clicks/taps hitting
+	/// a surface point start a double click/tap, while it is pending, orbiting by cursor/finger
+	/// movements of a few pixels is suppressed.
+	///
+	/// [`TrackballMessage::target`]: crate::TrackballMessage::target
+	pub double_click: f32,
+	/// Whether flying the target to the picked surface point adjusts the distance to the depth of
+	/// the picked point, so the eye moves laterally only. Otherwise, the distance is preserved.
+	/// Default is `true`.
+	pub double_click_distance: bool,
+	/// Duration of flying the target to the picked surface point in milliseconds. Default is
+	/// `500.0`.
+	pub double_click_duration: f32,
 
-	/// Mouse button used to slide camera. Default is [`MouseButton::Right`].
-	pub slide_button: Option<MouseButton>,
-	/// Key used to slide left. Default is [`KeyCode::KeyS`].
-	pub slide_left_key: Option<KeyCode>,
-	/// Key used to slide right. Default is [`KeyCode::KeyF`].
-	pub slide_right_key: Option<KeyCode>,
-	/// Key used to slide up. Default is [`KeyCode::KeyE`].
-	pub slide_up_key: Option<KeyCode>,
-	/// Key used to slide up. Default is [`KeyCode::KeyD`].
-	pub slide_down_key: Option<KeyCode>,
-	/// Key used to slide far. Default is [`KeyCode::KeyG`].
-	pub slide_far_key: Option<KeyCode>,
-	/// Key used to slide near. Default is [`KeyCode::KeyV`].
-	pub slide_near_key: Option<KeyCode>,
+	/// Input bindings of trackball actions.
+	pub bindings: TrackballBindings,
 
-	/// Key used to scale/zoom in. Default is [`KeyCode::KeyH`].
-	pub scale_in_key: Option<KeyCode>,
-	/// Key used to scale/zoom out. Default is [`KeyCode::KeyN`].
-	pub scale_out_key: Option<KeyCode>,
+	/// Gamepad entity driving this controller. Default is `None`.
+	///
+	/// If `None`, any gamepad drives the controller of the currently focused viewport. If `Some`,
+	/// the selected gamepad drives this controller regardless of the focused viewport (e.g., for
+	/// split-screen setups with one gamepad per viewport).
+	pub gamepad: Option<Entity>,
+	/// Dead zone of gamepad sticks and triggers from `0.0` to `1.0`. Default is `0.1`.
+	///
+	/// Deflections within the dead zone are ignored whereas deflections beyond it are rescaled to
+	/// start from zero, so the resulting velocity is continuous.
+	pub gamepad_dead_zone: f32,
+	/// Gamepad stick axes used to orbit at [`Self::velocity`] times deflection or to look around
+	/// while [`TrackballAction::First`] is active. Default is [`GamepadAxis::LeftStickX`] and
+	/// [`GamepadAxis::LeftStickY`].
+	pub orbit_gamepad_axes: Option<[GamepadAxis; 2]>,
+	/// Gamepad stick axes used to slide at [`Self::velocity`] times deflection. Default is
+	/// [`GamepadAxis::RightStickX`] and [`GamepadAxis::RightStickY`].
+	pub slide_gamepad_axes: Option<[GamepadAxis; 2]>,
+	/// Gamepad trigger used to scale/zoom in at [`Self::velocity`] times deflection. Default is
+	/// [`GamepadButton::RightTrigger2`].
+	pub scale_in_gamepad_button: Option<GamepadButton>,
+	/// Gamepad trigger used to scale/zoom out at [`Self::velocity`] times deflection. Default is
+	/// [`GamepadButton::LeftTrigger2`].
+	pub scale_out_gamepad_button: Option<GamepadButton>,
 }
 
 impl TrackballInput {
@@ -149,13 +208,14 @@ impl TrackballInput {
 	///
 	/// This mapping is symmetric to the `ijkl`/`hn` orbit mapping but less intuitive to gamers
 	/// compared with [`Self::map_wasd`].
-	pub const fn map_esdf(&mut self) {
-		self.slide_up_key = Some(KeyCode::KeyE);
-		self.slide_down_key = Some(KeyCode::KeyD);
-		self.slide_left_key = Some(KeyCode::KeyS);
-		self.slide_right_key = Some(KeyCode::KeyF);
-		self.slide_far_key = Some(KeyCode::KeyG);
-		self.slide_near_key = Some(KeyCode::KeyV);
+	pub fn map_esdf(&mut self) {
+		let bindings = &mut self.bindings;
+		bindings.set(TrackballAction::SlideUp, [KeyCode::KeyE]);
+		bindings.set(TrackballAction::SlideDown, [KeyCode::KeyD]);
+		bindings.set(TrackballAction::SlideLeft, [KeyCode::KeyS]);
+		bindings.set(TrackballAction::SlideRight, [KeyCode::KeyF]);
+		bindings.set(TrackballAction::SlideFar, [KeyCode::KeyG]);
+		bindings.set(TrackballAction::SlideNear, [KeyCode::KeyV]);
 	}
 	/// Maps `wasd`/`Space`/`ControlLeft` to slide operations.
 	///
@@ -170,13 +230,141 @@ impl TrackballInput {
 	///
 	/// This mapping isn't symmetric to the `ijkl`/`hn` orbit mapping but more intuitive to gamers
 	/// compared with [`Self::map_esdf`].
-	pub const fn map_wasd(&mut self) {
-		self.slide_up_key = Some(KeyCode::Space);
-		self.slide_down_key = Some(KeyCode::ControlLeft);
-		self.slide_left_key = Some(KeyCode::KeyA);
-		self.slide_right_key = Some(KeyCode::KeyD);
-		self.slide_far_key = Some(KeyCode::KeyW);
-		self.slide_near_key = Some(KeyCode::KeyS);
+	pub fn map_wasd(&mut self) {
+		let bindings = &mut self.bindings;
+		bindings.set(TrackballAction::SlideUp, [KeyCode::Space]);
+		bindings.set(TrackballAction::SlideDown, [KeyCode::ControlLeft]);
+		bindings.set(TrackballAction::SlideLeft, [KeyCode::KeyA]);
+		bindings.set(TrackballAction::SlideRight, [KeyCode::KeyD]);
+		bindings.set(TrackballAction::SlideFar, [KeyCode::KeyW]);
+		bindings.set(TrackballAction::SlideNear, [KeyCode::KeyS]);
+	}
+	/// Maps `wasd`/`qe` to slide operations and `ShiftLeft` to boost for fly mode.
+	///
+	/// Key         | Operation
+	/// ----------- | ---------------------------
+	/// `w`         | Slides far (forward).
+	/// `a`         | Slides left.
+	/// `s`         | Slides near (backward).
+	/// `d`         | Slides right.
+	/// `e`         | Slides up.
+	/// `q`         | Slides down.
+	/// `ShiftLeft` | Boosts slide speed.
+	///
+	/// Looking around is unbound from `ShiftLeft` as it is always active in fly mode. Other key
+	/// bindings are preserved. See [`TrackballMode::Fly`].
+	///
+	/// [`TrackballMode::Fly`]: crate::TrackballMode::Fly
+	pub fn map_fly(&mut self) {
+		let bindings = &mut self.bindings;
+		bindings.unbind(KeyCode::ShiftLeft);
+		bindings.set(TrackballAction::SlideUp, [KeyCode::KeyE]);
+		bindings.set(TrackballAction::SlideDown, [KeyCode::KeyQ]);
+		bindings.set(TrackballAction::SlideLeft, [KeyCode::KeyA]);
+		bindings.set(TrackballAction::SlideRight, [KeyCode::KeyD]);
+		bindings.set(TrackballAction::SlideFar, [KeyCode::KeyW]);
+		bindings.set(TrackballAction::SlideNear, [KeyCode::KeyS]);
+		bindings.set(TrackballAction::Boost, [KeyCode::ShiftLeft]);
+	}
+	/// Maps `wasd` to slide operations, `Space` to jump, and `ShiftLeft` to boost for walk mode.
+	///
+	/// Key         | Operation
+	/// ----------- | ---------------------------
+	/// `w`         | Walks forward.
+	/// `a`         | Walks left.
+	/// `s`         | Walks backward.
+	/// `d`         | Walks right.
+	/// `Space`     | Jumps.
+	/// `ShiftLeft` | Boosts walk speed.
+	///
+	/// Looking around is unbound from `ShiftLeft` as it is always active in walk mode. Other key
+	/// bindings are preserved. See [`TrackballMode::Walk`].
+	///
+	/// [`TrackballMode::Walk`]: crate::TrackballMode::Walk
+	pub fn map_walk(&mut self) {
+		self.map_fly();
+		self.bindings
+			.set(TrackballAction::SlideUp, [KeyCode::Space]);
+		self.bindings.clear(TrackballAction::SlideDown);
+	}
+	/// Maps Blender-like mouse navigation.
+	///
+	/// Mouse                         | Operation
+	/// ----------------------------- | ------------------------------
+	/// Middle Press + Drag           | Orbits around target.
+	/// Middle Double Click           | Flies target to picked point.
+	/// `Shift` + Middle Press + Drag | Slides trackball on focus plane.
+	/// `Ctrl` + Middle Press + Drag  | Scales distance zooming in/out.
+	/// Scroll In/Out                 | Scales distance zooming in/out.
+	///
+	/// Left and right mouse buttons are unbound as they are usually used for selection and context
+	/// menus. Looking around is unbound from mouse buttons and `ShiftLeft` as `Shift` is a modifier
+	/// here. Other key bindings are preserved.
+	pub fn map_blender(&mut self) {
+		self.map_mouse(
+			MouseButton::Middle.into(),
+			TrackballBinding::chord(TrackballModifiers::SHIFT, MouseButton::Middle),
+			TrackballBinding::chord(TrackballModifiers::CONTROL, MouseButton::Middle),
+		);
+	}
+	/// Maps Maya-like mouse navigation.
+	///
+	/// Mouse                       | Operation
+	/// --------------------------- | ------------------------------
+	/// `Alt` + Left Press + Drag   | Orbits around target.
+	/// `Alt` + Left Double Click   | Flies target to picked point.
+	/// `Alt` + Middle Press + Drag | Slides trackball on focus plane.
+	/// `Alt` + Right Press + Drag  | Scales distance zooming in/out.
+	/// Scroll In/Out               | Scales distance zooming in/out.
+	///
+	/// Mouse buttons without `Alt` are unbound as they are usually used for selection and context
+	/// menus. Looking around is unbound from mouse buttons and `ShiftLeft`. Snapping is rebound to
+	/// `ControlLeft` as `Alt` is a modifier here. Other key bindings are preserved.
+	pub fn map_maya(&mut self) {
+		self.map_mouse(
+			TrackballBinding::chord(TrackballModifiers::ALT, MouseButton::Left),
+			TrackballBinding::chord(TrackballModifiers::ALT, MouseButton::Middle),
+			TrackballBinding::chord(TrackballModifiers::ALT, MouseButton::Right),
+		);
+		self.bindings
+			.set(TrackballAction::Snap, [KeyCode::ControlLeft]);
+	}
+	/// Maps CAD-like (e.g., `SolidWorks`) mouse navigation.
+	///
+	/// Mouse                         | Operation
+	/// ----------------------------- | ------------------------------
+	/// Middle Press + Drag           | Orbits around target.
+	/// Middle Double Click           | Flies target to picked point.
+	/// `Ctrl` + Middle Press + Drag  | Slides trackball on focus plane.
+	/// `Shift` + Middle Press + Drag | Scales distance zooming in/out.
+	/// Scroll In/Out                 | Scales distance zooming in/out.
+	///
+	/// Left and right mouse buttons are unbound as they are usually used for selection and context
+	/// menus. Looking around is unbound from mouse buttons and `ShiftLeft` as `Shift` is a modifier
+	/// here. Other key bindings are preserved.
+	pub fn map_cad(&mut self) {
+		self.map_mouse(
+			MouseButton::Middle.into(),
+			TrackballBinding::chord(TrackballModifiers::CONTROL, MouseButton::Middle),
+			TrackballBinding::chord(TrackballModifiers::SHIFT, MouseButton::Middle),
+		);
+	}
+	fn map_mouse(
+		&mut self,
+		orbit: TrackballBinding,
+		slide: TrackballBinding,
+		scale: TrackballBinding,
+	) {
+		let shift = TrackballBinding::from(KeyCode::ShiftLeft);
+		self.bindings.retain(|action, binding| {
+			let first = action == TrackballAction::First && *binding == shift;
+			let mouse = matches!(binding.button, TrackballButton::Mouse(_));
+			!first && !mouse
+		});
+		self.bindings.bind(TrackballAction::Orbit, orbit);
+		self.bindings.bind(TrackballAction::Target, orbit);
+		self.bindings.bind(TrackballAction::Slide, slide);
+		self.bindings.bind(TrackballAction::Scale, scale);
 	}
 }
 
@@ -195,6 +383,7 @@ impl Default for TrackballInput {
 			first_mouse_transmission: 1.0,
 			orbit_mouse_transmission: 1.0,
 			slide_mouse_transmission: 1.0,
+			scale_mouse_transmission: 1.0,
 			scale_wheel_transmission: 1.0,
 
 			first_touch_transmission: 1.0,
@@ -203,39 +392,32 @@ impl Default for TrackballInput {
 			slide_touch_transmission: 1.0,
 			scale_touch_transmission: 1.0,
 
-			focus: true,
-
-			gamer_key: Some(KeyCode::KeyM),
-
-			ortho_key: Some(KeyCode::KeyP),
+			first_gamepad_transmission: 1.0,
+			orbit_gamepad_transmission: 1.0,
+			slide_gamepad_transmission: 1.0,
+			scale_gamepad_transmission: 1.0,
 
-			reset_key: Some(KeyCode::Enter),
+			inertia: 0.0,
 
-			first_key: Some(KeyCode::ShiftLeft),
-			first_button: Some(MouseButton::Middle),
-			first_left_key: Some(KeyCode::ArrowLeft),
-			first_right_key: Some(KeyCode::ArrowRight),
-			first_up_key: Some(KeyCode::ArrowUp),
-			first_down_key: Some(KeyCode::ArrowDown),
+			orbit_snap: PI / 12.0,
+			screw_snap: PI / 12.0,
+			first_snap: PI / 12.0,
 
-			orbit_button: Some(MouseButton::Left),
-			screw_left_key: Some(KeyCode::KeyU),
-			screw_right_key: Some(KeyCode::KeyO),
-			orbit_left_key: Some(KeyCode::KeyJ),
-			orbit_right_key: Some(KeyCode::KeyL),
-			orbit_up_key: Some(KeyCode::KeyI),
-			orbit_down_key: Some(KeyCode::KeyK),
+			focus: true,
+			pick_orbit: false,
+			pick_scale: false,
+			double_click: 0.0,
+			double_click_distance: true,
+			double_click_duration: 500.0,
 
-			slide_button: Some(MouseButton::Right),
-			slide_up_key: Some(KeyCode::KeyE),
-			slide_down_key: Some(KeyCode::KeyD),
-			slide_left_key: Some(KeyCode::KeyS),
-			slide_right_key: Some(KeyCode::KeyF),
-			slide_far_key: Some(KeyCode::KeyG),
-			slide_near_key: Some(KeyCode::KeyV),
+			bindings: TrackballBindings::default(),
 
-			scale_in_key: Some(KeyCode::KeyH),
-			scale_out_key: Some(KeyCode::KeyN),
+			gamepad: None,
+			gamepad_dead_zone: 0.1,
+			orbit_gamepad_axes: Some([GamepadAxis::LeftStickX, GamepadAxis::LeftStickY]),
+			slide_gamepad_axes: Some([GamepadAxis::RightStickX, GamepadAxis::RightStickY]),
+			scale_in_gamepad_button: Some(GamepadButton::RightTrigger2),
+			scale_out_gamepad_button: Some(GamepadButton::LeftTrigger2),
 		}
 	}
 }
@@ -1,10 +1,23 @@
-use bevy::{
-	prelude::*,
-	window::{CursorGrabMode, CursorOptions},
+use std::f32::consts::PI;
+
+use bevy::prelude::*;
+use trackball::nalgebra::{Point3, Unit, UnitQuaternion, Vector3};
+
+use super::Actions;
+use crate::{
+	StandardView, TrackballAction, TrackballCamera, TrackballController, TrackballMessage,
+	TrackballMode,
 };
-use trackball::nalgebra::{Point3, Unit, UnitQuaternion};
 
-use crate::{TrackballCamera, TrackballController, TrackballMessage};
+/// Slide actions toggled by [`TrackballAction::Gamer`].
+const SLIDES: [TrackballAction; 6] = [
+	TrackballAction::SlideUp,
+	TrackballAction::SlideDown,
+	TrackballAction::SlideLeft,
+	TrackballAction::SlideRight,
+	TrackballAction::SlideFar,
+	TrackballAction::SlideNear,
+];
 
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::too_many_lines)]
@@ -13,114 +26,176 @@ pub fn key(
 	trackball_messages: &mut MessageWriter<TrackballMessage>,
 	trackball: &TrackballCamera,
 	controller: &mut TrackballController,
-	cursor_options: &mut CursorOptions,
-	key_input: &Res<ButtonInput<KeyCode>>,
-	mouse_input: &Res<ButtonInput<MouseButton>>,
+	actions: &Actions,
 	zat: f32,
 	w: f32,
 	v: f32,
 	t: f32,
 ) {
-	let pressed = |key: Option<KeyCode>| key.is_some_and(|key| key_input.pressed(key));
-	let just_pressed = |key: Option<KeyCode>| key.is_some_and(|key| key_input.just_pressed(key));
-	let just_released = |key: Option<KeyCode>| key.is_some_and(|key| key_input.just_released(key));
-	let pressed_button =
-		|button: Option<MouseButton>| button.is_some_and(|button| mouse_input.pressed(button));
-	if just_pressed(controller.input.reset_key) {
+	let pressed = |action| actions.pressed(action);
+	let just_pressed = |action| actions.just_pressed(action);
+	let just_released = |action| actions.just_released(action);
+	if just_pressed(TrackballAction::Reset) {
 		trackball_messages.write(TrackballMessage::reset(group));
 	}
-	if just_pressed(controller.input.ortho_key) {
+	let orbits = controller.mode.orbits();
+	if !orbits && !trackball.scope.ortho() {
+		trackball_messages.write(TrackballMessage::ortho(group, Some(true)));
+	} else if just_pressed(TrackballAction::Ortho) && orbits {
 		trackball_messages.write(TrackballMessage::ortho(group, None));
 	}
-	if just_pressed(controller.input.gamer_key) {
-		if controller.input.slide_far_key == Some(KeyCode::KeyW) {
-			controller.input.map_esdf();
+	if !orbits && !trackball.is_flying() {
+		let frame = &trackball.frame;
+		let (roll_axis, up) = (frame.roll_axis(), Vector3::from(*trackball.view_up));
+		if roll_axis.dot(&up) < 1.0 - f32::EPSILON.sqrt() {
+			let rot = UnitQuaternion::rotation_between(&roll_axis, &up)
+				.unwrap_or_else(|| UnitQuaternion::from_axis_angle(&frame.pitch_axis(), PI));
+			let rot = frame.view().rotation.inverse() * rot * frame.view().rotation;
+			trackball_messages
+				.write(TrackballMessage::orbit(group, rot, Point3::origin()).automatic());
+		}
+	}
+	for (action, view) in [
+		(TrackballAction::ViewFront, StandardView::Front),
+		(TrackballAction::ViewBack, StandardView::Back),
+		(TrackballAction::ViewLeft, StandardView::Left),
+		(TrackballAction::ViewRight, StandardView::Right),
+		(TrackballAction::ViewTop, StandardView::Top),
+		(TrackballAction::ViewBottom, StandardView::Bottom),
+		(TrackballAction::ViewIsometric, StandardView::Isometric),
+	] {
+		if just_pressed(action) && orbits {
+			trackball_messages.write(TrackballMessage::view(group, view));
+		}
+	}
+	for slot in 1..=9 {
+		if just_pressed(TrackballAction::StoreBookmark(slot)) {
+			trackball_messages.write(TrackballMessage::store(group, slot));
+		}
+		if just_pressed(TrackballAction::RecallBookmark(slot)) {
+			trackball_messages.write(TrackballMessage::recall(group, slot));
+		}
+	}
+	if just_pressed(TrackballAction::Gamer) {
+		if let Some(bindings) = controller.gamer.take() {
+			for (action, bindings) in SLIDES.into_iter().zip(bindings) {
+				controller.input.bindings.set(action, bindings);
+			}
 		} else {
-			controller.input.map_wasd();
+			controller.gamer =
+				Some(SLIDES.map(|action| controller.input.bindings.get(action).to_vec()));
+			if matches!(
+				controller.mode,
+				TrackballMode::Fly { .. } | TrackballMode::Walk { .. }
+			) {
+				controller.input.map_esdf();
+			} else {
+				controller.input.map_wasd();
+			}
 		}
 	}
-	for (key, vec) in [
-		(controller.input.slide_far_key, Vec3::NEG_Z),
-		(controller.input.slide_near_key, Vec3::Z),
-		(controller.input.slide_left_key, Vec3::NEG_X),
-		(controller.input.slide_right_key, Vec3::X),
-		(controller.input.slide_up_key, Vec3::Y),
-		(controller.input.slide_down_key, Vec3::NEG_Y),
+	let walk = matches!(controller.mode, TrackballMode::Walk { .. });
+	let speed = controller.mode.speed(pressed(TrackballAction::Boost));
+	for (action, vec) in [
+		(TrackballAction::SlideFar, Vec3::NEG_Z),
+		(TrackballAction::SlideNear, Vec3::Z),
+		(TrackballAction::SlideLeft, Vec3::NEG_X),
+		(TrackballAction::SlideRight, Vec3::X),
+		(TrackballAction::SlideUp, Vec3::Y),
+		(TrackballAction::SlideDown, Vec3::NEG_Y),
 	] {
-		if pressed(key) {
-			let v = v * controller.input.slide_key_transmission;
+		if pressed(action) && !walk {
+			let v = v * controller.input.slide_key_transmission * speed;
 			trackball_messages.write(TrackballMessage::slide(group, (vec * v * t).into()));
 		}
 	}
-	for (num, &(key, vec)) in [
-		(controller.input.screw_left_key, Vec3::Z),
-		(controller.input.screw_right_key, Vec3::NEG_Z),
-		(controller.input.orbit_left_key, Vec3::NEG_Y),
-		(controller.input.orbit_right_key, Vec3::Y),
-		(controller.input.orbit_up_key, Vec3::NEG_X),
-		(controller.input.orbit_down_key, Vec3::X),
+	for (num, &(action, vec)) in [
+		(TrackballAction::ScrewLeft, Vec3::Z),
+		(TrackballAction::ScrewRight, Vec3::NEG_Z),
+		(TrackballAction::OrbitLeft, Vec3::NEG_Y),
+		(TrackballAction::OrbitRight, Vec3::Y),
+		(TrackballAction::OrbitUp, Vec3::NEG_X),
+		(TrackballAction::OrbitDown, Vec3::X),
 	]
 	.iter()
 	.enumerate()
 	{
-		if pressed(key) {
+		if pressed(action)
+			&& if num < 2 {
+				controller.mode.twists()
+			} else {
+				orbits
+			} {
 			let w = w * if num < 2 {
 				controller.input.screw_key_transmission
 			} else {
 				controller.input.orbit_key_transmission
 			};
-			trackball_messages.write(TrackballMessage::orbit(
-				group,
-				UnitQuaternion::from_axis_angle(&Unit::new_unchecked(vec.into()), w * t),
-				Point3::origin(),
-			));
+			let rot = if num < 2 {
+				let step = controller.input.screw_snap;
+				controller
+					.snap
+					.screw(vec.z * w * t, step)
+					.map(|ang| UnitQuaternion::from_axis_angle(&Vector3::z_axis(), ang))
+			} else {
+				let rot = UnitQuaternion::from_axis_angle(&Unit::new_unchecked(vec.into()), w * t);
+				let (yaw, pitch) = (vec.y * w * t, vec.x * w * t);
+				let frame = &trackball.frame;
+				let rot = (controller.mode)
+					.orbit(frame, &mut controller.tilt, yaw, pitch)
+					.unwrap_or(rot);
+				let yaw_axis = controller.mode.yaw_axis(frame);
+				controller
+					.snap
+					.orbit(rot, &yaw_axis, controller.input.orbit_snap)
+			};
+			if let Some(rot) = rot {
+				trackball_messages.write(TrackballMessage::orbit(group, rot, Point3::origin()));
+			}
 		}
 	}
-	for key in [
-		controller.input.first_left_key,
-		controller.input.first_right_key,
-		controller.input.first_up_key,
-		controller.input.first_down_key,
+	for action in [
+		TrackballAction::FirstLeft,
+		TrackballAction::FirstRight,
+		TrackballAction::FirstUp,
+		TrackballAction::FirstDown,
 	] {
-		if just_pressed(key) {
+		if just_pressed(action) {
 			controller.first_count += 1;
 		}
-		if just_released(key) && controller.first_count != 0 {
+		if just_released(action) && controller.first_count != 0 {
 			controller.first_count -= 1;
 		}
 	}
-	if !pressed(controller.input.first_key) && !pressed_button(controller.input.first_button) {
-		if controller.first_count == 0 {
+	if !pressed(TrackballAction::First) {
+		if controller.first_count == 0 && !controller.mode.looks_around() || !orbits {
 			controller.first.discard();
 		} else if !controller.first.enabled() {
 			controller.first.capture(trackball.frame.yaw_axis());
 		}
 	}
-	for (key, vec) in [
-		(controller.input.first_left_key, Vec2::Y),
-		(controller.input.first_right_key, Vec2::NEG_Y),
-		(controller.input.first_up_key, Vec2::X),
-		(controller.input.first_down_key, Vec2::NEG_X),
+	for (action, vec) in [
+		(TrackballAction::FirstLeft, Vec2::Y),
+		(TrackballAction::FirstRight, Vec2::NEG_Y),
+		(TrackballAction::FirstUp, Vec2::X),
+		(TrackballAction::FirstDown, Vec2::NEG_X),
 	] {
-		if pressed(key) {
+		if pressed(action)
+			&& let Some(&yaw_axis) = controller.first.yaw_axis()
+		{
 			let w = w * controller.input.first_key_transmission;
 			let ang = vec * w * t;
-			let yaw_axis = *controller.first.yaw_axis().unwrap();
-			trackball_messages.write(TrackballMessage::first(group, ang.x, ang.y, yaw_axis));
+			if let Some((pitch, yaw)) =
+				controller
+					.snap
+					.first(ang.x, ang.y, controller.input.first_snap)
+			{
+				trackball_messages.write(TrackballMessage::first(group, pitch, yaw, yaw_axis));
+			}
 		}
 	}
-	if just_pressed(controller.input.first_key) {
-		controller.first.capture(trackball.frame.yaw_axis());
-		cursor_options.grab_mode = CursorGrabMode::Locked;
-		cursor_options.visible = false;
-	}
-	if just_released(controller.input.first_key) {
-		controller.first.discard();
-		cursor_options.grab_mode = CursorGrabMode::None;
-		cursor_options.visible = true;
-	}
 	controller.scale.set_denominator(zat);
-	if pressed(controller.input.scale_in_key) {
+	if pressed(TrackballAction::ScaleIn) {
 		let v = v * controller.input.scale_key_transmission;
 		trackball_messages.write(TrackballMessage::scale(
 			group,
@@ -128,7 +203,7 @@ pub fn key(
 			Point3::origin(),
 		));
 	}
-	if pressed(controller.input.scale_out_key) {
+	if pressed(TrackballAction::ScaleOut) {
 		let v = v * controller.input.scale_key_transmission;
 		trackball_messages.write(TrackballMessage::scale(
 			group,
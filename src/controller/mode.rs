@@ -0,0 +1,278 @@
+use std::{f32::consts::FRAC_PI_2, fmt, sync::Arc};
+
+use bevy::prelude::*;
+use trackball::{
+	Frame,
+	nalgebra::{Unit, UnitQuaternion, Vector3},
+};
+
+use crate::clamps::{Colliders, Terrain};
+
+/// Camera mode of [`TrackballController`] defining how orbit input is interpreted.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_trackball::prelude::*;
+///
+/// let controller = TrackballController::default().with_mode(TrackballMode::turntable(Dir3::Y));
+/// ```
+///
+/// [`TrackballController`]: super::TrackballController
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub enum TrackballMode {
+	/// Trackball mode orbiting via exponential map with free roll (default).
+	#[default]
+	Trackball,
+	/// Turntable mode orbiting about a fixed up axis without accumulating roll.
+	///
+	/// Horizontal drags yaw about the `up` axis in world space whereas vertical drags pitch about
+	/// the horizontal axis of the camera. The pitch angle of the eye above the target is limited
+	/// from `min` to `max` in radians where zero means eye and target are level.
+	Turntable {
+		/// Fixed up axis in world space.
+		up: Dir3,
+		/// Minimum pitch angle in radians.
+		min: f32,
+		/// Maximum pitch angle in radians.
+		max: f32,
+	},
+	/// Fly mode looking around by mouse and sliding relative to the view by keys.
+	///
+	/// Looking around is always active and locks the cursor. Sliding by keys is sped up by `speed`
+	/// relative to [`TrackballVelocity`] and additionally by `boost` while
+	/// [`TrackballAction::Boost`] is active. Scrolling adjusts `speed` instead of scaling. See
+	/// [`TrackballInput::map_fly`] for a suitable key mapping.
+	///
+	/// [`TrackballVelocity`]: super::TrackballVelocity
+	/// [`TrackballAction::Boost`]: super::TrackballAction::Boost
+	/// [`TrackballInput::map_fly`]: super::TrackballInput::map_fly
+	Fly {
+		/// Slide speed factor adjusted by scrolling.
+		speed: f32,
+		/// Slide speed factor while boosting.
+		boost: f32,
+	},
+	/// Walk mode like fly mode but sliding horizontally and keeping the eye above ground.
+	///
+	/// Sliding by keys is restricted to the horizontal plane orthogonal to the yaw axis captured
+	/// from [`Frame::yaw_axis`] whenever looking around starts. The eye falls by `gravity` until it
+	/// is `height` above `ground` which is probed from one `height` above the eye, so the eye
+	/// steps up on obstacles lower than `height`. Sliding up by keys (e.g., `Space` of
+	/// [`TrackballInput::map_walk`]) jumps at `jump` velocity while on ground. Scrolling adjusts
+	/// `speed` instead of scaling.
+	///
+	/// [`TrackballInput::map_walk`]: super::TrackballInput::map_walk
+	Walk {
+		/// Ground below the eye.
+		ground: TrackballGround,
+		/// Eye height above ground in world units.
+		height: f32,
+		/// Jump velocity in world units per second where zero disables jumping.
+		jump: f32,
+		/// Gravitational acceleration in world units per square second.
+		gravity: f32,
+		/// Slide speed factor adjusted by scrolling.
+		speed: f32,
+		/// Slide speed factor while boosting.
+		boost: f32,
+	},
+	/// Map mode panning and zooming a top-down view without orbiting.
+	///
+	/// Dragging pans, scrolling and pinching scale about the cursor and fingers, respectively,
+	/// whereas orbiting and looking around are disabled. Twisting two fingers and screwing by keys
+	/// rotate about the view axis if `twist` is enabled. The view axis is kept top-down along
+	/// [`TrackballCamera::view_up`] by orbiting the eye above the target whenever it deviates
+	/// (e.g., when entering map mode). The projection mode is kept orthographic, so
+	/// [`TrackballAction::Ortho`] and the standard views are ignored, where fixing [`Fixed::Upp`]
+	/// of [`Scope`] additionally preserves the map scale when the viewport is resized.
+	///
+	/// ```
+	/// use bevy::prelude::*;
+	/// use bevy_trackball::{prelude::*, trackball::Fixed};
+	///
+	/// let mut scope = Scope::default();
+	/// scope.set_fov(Fixed::Upp(1e-3));
+	/// scope.set_ortho(true);
+	/// let camera =
+	/// 	TrackballCamera::look_at(Vec3::ZERO, Vec3::Y * 10.0, Vec3::NEG_Z).with_scope(scope);
+	/// let controller = TrackballController::default().with_mode(TrackballMode::map());
+	/// ```
+	///
+	/// [`TrackballCamera::view_up`]: crate::TrackballCamera::view_up
+	/// [`TrackballAction::Ortho`]: crate::TrackballAction::Ortho
+	/// [`Fixed::Upp`]: trackball::Fixed::Upp
+	/// [`Scope`]: trackball::Scope
+	Map {
+		/// Whether to rotate about the view axis.
+		twist: bool,
+	},
+}
+
+impl TrackballMode {
+	/// Turntable mode about `up` axis with pitch angle limited from -90 to 90 degrees.
+	#[must_use]
+	pub const fn turntable(up: Dir3) -> Self {
+		Self::Turntable {
+			up,
+			min: -FRAC_PI_2,
+			max: FRAC_PI_2,
+		}
+	}
+	/// Fly mode with unit speed and boost factor of four.
+	#[must_use]
+	pub const fn fly() -> Self {
+		Self::Fly {
+			speed: 1.0,
+			boost: 4.0,
+		}
+	}
+	/// Walk mode on `ground` at eye `height` without jumping, earth's gravity, unit speed, and
+	/// boost factor of two.
+	#[must_use]
+	pub const fn walk(ground: TrackballGround, height: f32) -> Self {
+		Self::Walk {
+			ground,
+			height,
+			jump: 0.0,
+			gravity: 9.81,
+			speed: 1.0,
+			boost: 2.0,
+		}
+	}
+	/// Map mode without twisting.
+	#[must_use]
+	pub const fn map() -> Self {
+		Self::Map { twist: false }
+	}
+	/// Whether looking around is always active as in fly and walk mode.
+	#[must_use]
+	pub const fn looks_around(&self) -> bool {
+		matches!(self, Self::Fly { .. } | Self::Walk { .. })
+	}
+	/// Whether orbiting and looking around are enabled as in all but map mode.
+	pub(crate) const fn orbits(&self) -> bool {
+		!matches!(self, Self::Map { .. })
+	}
+	/// Whether rotating about the view axis is enabled as in all but map mode without twisting.
+	pub(crate) const fn twists(&self) -> bool {
+		!matches!(self, Self::Map { twist: false })
+	}
+	/// Slide speed factor by keys whether `boost` is active.
+	pub(crate) const fn speed(&self, boost: bool) -> f32 {
+		match *self {
+			Self::Fly { speed, boost: rat }
+			| Self::Walk {
+				speed, boost: rat, ..
+			} if boost => speed * rat,
+			Self::Fly { speed, .. } | Self::Walk { speed, .. } => speed,
+			_ => 1.0,
+		}
+	}
+	/// Mutable slide speed factor adjusted by scrolling.
+	pub(crate) const fn speed_mut(&mut self) -> Option<&mut f32> {
+		match self {
+			Self::Fly { speed, .. } | Self::Walk { speed, .. } => Some(speed),
+			_ => None,
+		}
+	}
+	/// Yaw axis in camera space of `frame`, i.e., the up axis in turntable mode or the local yaw
+	/// axis otherwise.
+	pub(crate) fn yaw_axis(&self, frame: &Frame<f32>) -> Unit<Vector3<f32>> {
+		if let Self::Turntable { up, .. } = *self {
+			frame.view().rotation.inverse() * Unit::new_unchecked(Vector3::from(up.as_vec3()))
+		} else {
+			frame.local_yaw_axis()
+		}
+	}
+	/// Orbit rotation in camera space of `yaw` and `pitch` angles or `None` if not in turntable
+	/// mode.
+	///
+	/// The `tilt` accumulates pitch angles not yet applied to `frame` within the current frame.
+	pub(crate) fn orbit(
+		&self,
+		frame: &Frame<f32>,
+		tilt: &mut f32,
+		yaw: f32,
+		pitch: f32,
+	) -> Option<UnitQuaternion<f32>> {
+		let Self::Turntable { up, min, max } = *self else {
+			return None;
+		};
+		let up = Unit::new_unchecked(Vector3::from(up.as_vec3()));
+		let old = frame.roll_axis().dot(&up).clamp(-1.0, 1.0).asin() + *tilt;
+		let new = (old - pitch).clamp(min.min(old), max.max(old));
+		*tilt += new - old;
+		let yaw_axis = self.yaw_axis(frame);
+		Some(
+			UnitQuaternion::from_axis_angle(&yaw_axis, yaw)
+				* UnitQuaternion::from_axis_angle(&Vector3::x_axis(), old - new),
+		)
+	}
+}
+
+/// Ground of [`TrackballMode::Walk`].
+///
+/// Returns the distance from a ray origin along a ray direction to the ground or `None` if there
+/// is no ground in this direction.
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_trackball::prelude::*;
+///
+/// let ground = TrackballGround::plane(Vec3::ZERO, Dir3::Y);
+/// assert_eq!(ground.distance(Vec3::new(1.0, 2.0, 3.0), Dir3::NEG_Y), Some(2.0));
+/// assert_eq!(ground.distance(Vec3::new(1.0, 2.0, 3.0), Dir3::Y), None);
+///
+/// let controller = TrackballController::map_walk(ground, 1.7);
+/// ```
+#[derive(Clone)]
+pub struct TrackballGround(Arc<dyn Fn(Vec3, Dir3) -> Option<f32> + Send + Sync>);
+
+impl TrackballGround {
+	/// Ground of `distance` function from ray origin along ray direction.
+	pub fn new(distance: impl Fn(Vec3, Dir3) -> Option<f32> + Send + Sync + 'static) -> Self {
+		Self(Arc::new(distance))
+	}
+	/// Ground plane through `point` with `normal`.
+	#[must_use]
+	pub fn plane(point: Vec3, normal: Dir3) -> Self {
+		Self::new(move |origin, dir| {
+			let cos = dir.dot(*normal);
+			(cos < 0.0).then(|| (point - origin).dot(*normal) / cos)
+		})
+	}
+	/// Ground of heightfield `terrain` assuming the y-axis as up axis.
+	#[must_use]
+	pub fn terrain(terrain: Terrain) -> Self {
+		Self::new(move |origin, _dir| Some(origin.y - terrain.height(origin.x, origin.z)))
+	}
+	/// Ground of `colliders` found by ray casting, see [`Colliders::cast_ray`].
+	///
+	/// Requires [`TrackballCollidersPlugin`] to keep `colliders` up to date with entities marked as
+	/// [`Collider`].
+	///
+	/// [`Collider`]: crate::clamps::Collider
+	/// [`TrackballCollidersPlugin`]: crate::clamps::TrackballCollidersPlugin
+	#[must_use]
+	pub fn colliders(colliders: Colliders) -> Self {
+		Self::new(move |origin, dir| colliders.cast_ray(origin, dir))
+	}
+	/// Distance from `origin` along `dir` to the ground.
+	#[must_use]
+	pub fn distance(&self, origin: Vec3, dir: Dir3) -> Option<f32> {
+		(self.0)(origin, dir)
+	}
+}
+
+impl PartialEq for TrackballGround {
+	fn eq(&self, other: &Self) -> bool {
+		Arc::ptr_eq(&self.0, &other.0)
+	}
+}
+
+impl fmt::Debug for TrackballGround {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("TrackballGround").finish_non_exhaustive()
+	}
+}
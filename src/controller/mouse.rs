@@ -8,7 +8,9 @@ use trackball::{
 	nalgebra::{Point2, Point3},
 };
 
-use super::{TrackballCamera, TrackballController, TrackballMessage};
+use super::{
+	Actions, TrackballAction, TrackballCamera, TrackballController, TrackballMessage, TrackballMode,
+};
 
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::too_many_lines)]
@@ -18,13 +20,14 @@ pub fn mouse(
 	trackball_events: &mut MessageWriter<TrackballMessage>,
 	trackball: &TrackballCamera,
 	controller: &mut TrackballController,
+	actions: &Actions,
 	window_id: Entity,
 	window: &Window,
 	cursor_options: &mut CursorOptions,
-	mouse_input: &Res<ButtonInput<MouseButton>>,
 	mut delta_events: MessageReader<MouseMotion>,
 	mut mouse_events: MessageReader<CursorMoved>,
 	mut wheel_events: MessageReader<MouseWheel>,
+	pick: Option<Vec3>,
 	zat: f32,
 	upp: f32,
 	min: Vec2,
@@ -33,17 +36,34 @@ pub fn mouse(
 ) {
 	let pos = Point2::from(window.cursor_position().map_or(max * 0.5, |pos| pos - min));
 	let max = max.into();
-	let just_pressed_button =
-		|button: Option<MouseButton>| button.is_some_and(|button| mouse_input.just_pressed(button));
-	let just_released_button = |button: Option<MouseButton>| {
-		button.is_some_and(|button| mouse_input.just_released(button))
+	let yaw_axis = controller.mode.yaw_axis(&trackball.frame);
+	let local = |enabled: bool| {
+		pick.filter(|_pick| enabled).map(|pick| {
+			let frame = &trackball.frame;
+			frame.view().rotation.inverse() * (Point3::from(pick) - frame.target().coords)
+		})
 	};
-	if just_pressed_button(controller.input.first_button) {
+	let orbits = controller.mode.orbits();
+	let orbit = |pressed: bool| pressed && orbits;
+	let pan = |slide: bool, orbit: bool| slide || orbit && !orbits;
+	let fly = controller.mode.looks_around();
+	let grab = |pressed: bool| pressed && !actions.gamepad(TrackballAction::First);
+	if fly != controller.fly {
+		controller.fly = fly;
+		let lock = fly || grab(actions.pressed(TrackballAction::First));
+		cursor_options.grab_mode = if lock {
+			CursorGrabMode::Locked
+		} else {
+			CursorGrabMode::None
+		};
+		cursor_options.visible = !lock;
+	}
+	if orbit(grab(actions.just_pressed(TrackballAction::First))) {
 		controller.first.capture(trackball.frame.yaw_axis());
 		cursor_options.grab_mode = CursorGrabMode::Locked;
 		cursor_options.visible = false;
 	}
-	if just_released_button(controller.input.first_button) {
+	if orbit(actions.just_released(TrackballAction::First)) && !fly {
 		controller.first.discard();
 		cursor_options.grab_mode = CursorGrabMode::None;
 		cursor_options.visible = true;
@@ -55,65 +75,137 @@ pub fn mouse(
 		{
 			let pitch = pitch * controller.input.first_mouse_transmission;
 			let yaw = yaw * controller.input.first_mouse_transmission;
-			trackball_events.write(TrackballMessage::first(group, pitch, yaw, *yaw_axis));
+			let yaw_axis = *yaw_axis;
+			if let Some((pitch, yaw)) =
+				controller
+					.snap
+					.first(pitch, yaw, controller.input.first_snap)
+			{
+				trackball_events.write(TrackballMessage::first(group, pitch, yaw, yaw_axis));
+			}
 		}
 	}
-	if just_pressed_button(controller.input.orbit_button) {
+	if orbit(actions.just_pressed(TrackballAction::Orbit)) {
+		controller.pivot = local(controller.input.pick_orbit).unwrap_or_else(Point3::origin);
 		controller.touch.compute(None, pos, 0);
-		controller.orbit.compute(&pos, &max);
+		controller.capture(pos, max);
 		commands
 			.entity(window_id)
 			.insert(CursorIcon::from(SystemCursorIcon::Pointer));
 	}
-	if just_released_button(controller.input.orbit_button) {
+	if actions.just_pressed(TrackballAction::Target) && controller.input.double_click > 0.0 {
+		if let Some(pick) = pick {
+			if controller.click.compute(pos) {
+				controller.touch.discard(None);
+				trackball_events.write(TrackballMessage::target(
+					group,
+					pick,
+					controller.input.double_click_distance,
+					controller.input.double_click_duration,
+				));
+			}
+		} else if let Some(vec) = controller.click.miss() {
+			trackball_events.write(TrackballMessage::slide(group, vec));
+		}
+	}
+	if actions.just_released(TrackballAction::Orbit)
+		|| actions.just_released(TrackballAction::Slide)
+		|| actions.just_released(TrackballAction::Scale)
+	{
+		controller.inertia.release();
+	}
+	if orbit(actions.just_released(TrackballAction::Orbit)) {
 		if let Some((_num, pos)) = controller.touch.discard(None)
 			&& controller.input.focus
 		{
 			let (pos, _max) = Image::transform_pos_and_max_wrt_max(&pos, &max);
 			let vec = pos.coords.scale(upp).push(0.0);
-			trackball_events.write(TrackballMessage::slide(group, vec));
+			if let Some(vec) = controller.click.defer(vec) {
+				trackball_events.write(TrackballMessage::slide(group, vec));
+			}
 		}
 		controller.orbit.discard();
+		if matches!(controller.mode, TrackballMode::Turntable { .. }) {
+			controller.slide.discard();
+		}
+		controller.pivot = Point3::origin();
 		commands
 			.entity(window_id)
 			.insert(CursorIcon::from(SystemCursorIcon::Default));
 	}
-	if just_pressed_button(controller.input.slide_button) {
+	if actions.just_released(TrackballAction::Target) {
+		controller.click.release();
+	}
+	if pan(
+		actions.just_pressed(TrackballAction::Slide),
+		actions.just_pressed(TrackballAction::Orbit),
+	) {
 		controller.slide.compute(pos);
 		commands
 			.entity(window_id)
 			.insert(CursorIcon::from(SystemCursorIcon::Move));
 	}
-	if just_released_button(controller.input.slide_button) {
+	if pan(
+		actions.just_released(TrackballAction::Slide),
+		actions.just_released(TrackballAction::Orbit),
+	) {
 		controller.slide.discard();
 		commands
 			.entity(window_id)
 			.insert(CursorIcon::from(SystemCursorIcon::Default));
 	}
+	if actions.just_pressed(TrackballAction::Scale) {
+		controller.zoom.compute(pos);
+		commands
+			.entity(window_id)
+			.insert(CursorIcon::from(SystemCursorIcon::NsResize));
+	}
+	if actions.just_released(TrackballAction::Scale) {
+		controller.zoom.discard();
+		commands
+			.entity(window_id)
+			.insert(CursorIcon::from(SystemCursorIcon::Default));
+	}
 	for mouse_event in mouse_events.read() {
 		let pos = mouse_event.position - min;
-		if controller
-			.input
-			.orbit_button
-			.is_some_and(|button| mouse_input.pressed(button))
+		if orbit(actions.pressed(TrackballAction::Orbit))
 			&& let Some((_num, pos, _rot, _rat)) = controller.touch.compute(None, pos.into(), 0)
-			&& let Some(rot) = controller.orbit.compute(&pos, &max)
+			&& let Some((rot, spin)) = controller.drag(
+				&trackball.frame,
+				pos,
+				max,
+				controller.input.orbit_mouse_transmission,
+			) && !controller.click.pending(pos)
+			&& let Some(rot) = controller
+				.snap
+				.orbit(rot, &yaw_axis, controller.input.orbit_snap)
 		{
-			let rot = rot.powf(controller.input.orbit_mouse_transmission);
-			trackball_events.write(TrackballMessage::orbit(group, rot, Point3::origin()));
+			if !controller.snap.enabled() {
+				controller.inertia.orbit(&spin, &controller.pivot);
+			}
+			trackball_events.write(TrackballMessage::orbit(group, rot, controller.pivot));
 		}
-		if controller
-			.input
-			.slide_button
-			.is_some_and(|button| mouse_input.pressed(button))
-			&& let Some(vec) = controller
-				.slide
-				.compute(pos.into())
-				.map(|vec| Image::transform_vec(&vec))
+		if pan(
+			actions.pressed(TrackballAction::Slide),
+			actions.pressed(TrackballAction::Orbit),
+		) && let Some(vec) = controller
+			.slide
+			.compute(pos.into())
+			.map(|vec| Image::transform_vec(&vec))
 		{
 			let vec = vec.scale(upp).push(0.0) * controller.input.slide_mouse_transmission;
+			controller.inertia.slide(&vec);
 			trackball_events.write(TrackballMessage::slide(group, vec));
 		}
+		if actions.pressed(TrackballAction::Scale)
+			&& let Some(vec) = controller.zoom.compute(pos.into())
+		{
+			controller.scale.set_denominator(zat);
+			let num = upp * vec.y * controller.input.scale_mouse_transmission;
+			let rat = controller.scale.compute(num);
+			controller.inertia.scale(rat);
+			trackball_events.write(TrackballMessage::scale(group, rat, Point3::origin()));
+		}
 	}
 	for &wheel_event in wheel_events.read() {
 		let num = match wheel_event.unit {
@@ -127,11 +219,15 @@ pub fn mouse(
 				upp * wheel_event.y
 			}
 		} * controller.input.scale_wheel_transmission;
-		let (pos, _max) = Image::transform_pos_and_max_wrt_max(&pos, &max);
-		trackball_events.write(TrackballMessage::scale(
-			group,
-			controller.scale.compute(num),
-			pos.coords.scale(upp).push(0.0).into(),
-		));
+		let rat = controller.scale.compute(num);
+		if let Some(speed) = controller.mode.speed_mut() {
+			*speed = (*speed / rat).clamp(1e-3, 1e3);
+			continue;
+		}
+		let pos = local(controller.input.pick_scale).unwrap_or_else(|| {
+			let (pos, _max) = Image::transform_pos_and_max_wrt_max(&pos, &max);
+			pos.coords.scale(upp).push(0.0).into()
+		});
+		trackball_events.write(TrackballMessage::scale(group, rat, pos));
 	}
 }
@@ -0,0 +1,102 @@
+use trackball::nalgebra::{Unit, UnitQuaternion, Vector2, Vector3};
+
+/// Angle snapping of rotations while [`TrackballAction::Snap`] is active.
+///
+/// Accumulates raw rotations since snapping has been enabled and yields the rotation from the
+/// previously to the newly snapped rotation whenever the accumulated angle crosses half a step.
+/// Passes raw rotations through while disabled or if the step is not positive.
+///
+/// [`TrackballAction::Snap`]: crate::TrackballAction::Snap
+#[derive(Debug, Clone, Default)]
+pub struct Snap {
+	enabled: bool,
+	/// Raw and snapped orbit rotation.
+	orbit: [UnitQuaternion<f32>; 2],
+	/// Yaw axis of orbit in camera space when snapping has started.
+	yaw_axis: Option<Unit<Vector3<f32>>>,
+	/// Raw and snapped screw angle.
+	screw: [f32; 2],
+	/// Raw and snapped pitch and yaw angles.
+	first: [Vector2<f32>; 2],
+}
+
+impl Snap {
+	/// Enables or disables snapping where disabling discards accumulated rotations.
+	pub fn update(&mut self, enabled: bool) {
+		if !enabled {
+			self.discard();
+		}
+		self.enabled = enabled;
+	}
+	/// Whether snapping is enabled.
+	pub const fn enabled(&self) -> bool {
+		self.enabled
+	}
+	/// Discards accumulated rotations.
+	pub fn discard(&mut self) {
+		*self = Self {
+			enabled: self.enabled,
+			..Self::default()
+		};
+	}
+	/// Snaps orbit `rot` in camera space to angle `step` in radians.
+	///
+	/// Like [`Self::first`], yaw about `yaw_axis` in camera space and pitch about the local pitch
+	/// axis snap separately where the yaw axis is captured when snapping starts.
+	pub fn orbit(
+		&mut self,
+		rot: UnitQuaternion<f32>,
+		yaw_axis: &Unit<Vector3<f32>>,
+		step: f32,
+	) -> Option<UnitQuaternion<f32>> {
+		if !self.enabled || step <= 0.0 {
+			return Some(rot);
+		}
+		let yaw_axis = *self.yaw_axis.get_or_insert(*yaw_axis);
+		let [raw, old] = &mut self.orbit;
+		*raw *= rot;
+		let old_dir = Vector3::z();
+		let new_dir = *raw * old_dir;
+		let elevation = |dir: &Vector3<f32>| yaw_axis.dot(dir).clamp(-1.0, 1.0).asin();
+		let pitch = elevation(&old_dir) - elevation(&new_dir);
+		let [old_dir, new_dir] = [old_dir, new_dir].map(|dir| dir - *yaw_axis * yaw_axis.dot(&dir));
+		let yaw = old_dir
+			.cross(&new_dir)
+			.dot(&yaw_axis)
+			.atan2(old_dir.dot(&new_dir));
+		let new = UnitQuaternion::from_axis_angle(&yaw_axis, round(yaw, step))
+			* UnitQuaternion::from_axis_angle(&Vector3::x_axis(), round(pitch, step));
+		let rot = old.inverse() * new;
+		*old = new;
+		(rot.angle() > f32::EPSILON.sqrt()).then_some(rot)
+	}
+	/// Snaps screw `ang` about view direction to angle `step` in radians.
+	pub fn screw(&mut self, ang: f32, step: f32) -> Option<f32> {
+		if !self.enabled || step <= 0.0 {
+			return Some(ang);
+		}
+		let [raw, old] = &mut self.screw;
+		*raw += ang;
+		let new = round(*raw, step);
+		let ang = new - *old;
+		*old = new;
+		(ang != 0.0).then_some(ang)
+	}
+	/// Snaps first-person `pitch` and `yaw` to angle `step` in radians.
+	pub fn first(&mut self, pitch: f32, yaw: f32, step: f32) -> Option<(f32, f32)> {
+		if !self.enabled || step <= 0.0 {
+			return Some((pitch, yaw));
+		}
+		let [raw, old] = &mut self.first;
+		*raw += Vector2::new(pitch, yaw);
+		let new = raw.map(|ang| round(ang, step));
+		let ang = new - *old;
+		*old = new;
+		(ang != Vector2::zeros()).then_some((ang.x, ang.y))
+	}
+}
+
+/// Rounds `ang` to nearest multiple of `step`.
+fn round(ang: f32, step: f32) -> f32 {
+	(ang / step).round() * step
+}
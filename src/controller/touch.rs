@@ -6,18 +6,24 @@ use trackball::{
 
 use super::{TrackballCamera, TrackballController, TrackballMessage};
 
-#[allow(clippy::too_many_arguments, clippy::similar_names)]
+#[allow(
+	clippy::too_many_arguments,
+	clippy::too_many_lines,
+	clippy::similar_names
+)]
 pub fn touch(
 	group: Entity,
 	trackball_events: &mut MessageWriter<TrackballMessage>,
 	trackball: &TrackballCamera,
 	controller: &mut TrackballController,
 	mut touch_events: MessageReader<TouchInput>,
+	pick: impl Fn(Option<u64>) -> Option<Vec3>,
 	upp: f32,
 	min: Vec2,
 	max: Vec2,
 ) {
 	let max = max.into();
+	let yaw_axis = controller.mode.yaw_axis(&trackball.frame);
 	for &touch_event in touch_events.read() {
 		let TouchInput {
 			id,
@@ -30,6 +36,22 @@ pub fn touch(
 			TouchPhase::Started | TouchPhase::Moved => {
 				if phase == TouchPhase::Started {
 					controller.slide.discard();
+					controller.inertia.discard();
+					if controller.touch.fingers() == 0 && controller.input.double_click > 0.0 {
+						if let Some(pick) = pick(Some(id)) {
+							if controller.tap.compute(pos.into()) {
+								trackball_events.write(TrackballMessage::target(
+									group,
+									pick,
+									controller.input.double_click_distance,
+									controller.input.double_click_duration,
+								));
+								continue;
+							}
+						} else if let Some(vec) = controller.tap.miss() {
+							trackball_events.write(TrackballMessage::slide(group, vec));
+						}
+					}
 				}
 				if let Some((num, pos, rot, rat)) =
 					controller.touch.compute(Some(id), pos.into(), 0)
@@ -41,12 +63,31 @@ pub fn touch(
 						{
 							let pitch = pitch * controller.input.first_touch_transmission;
 							let yaw = yaw * controller.input.first_touch_transmission;
-							trackball_events
-								.write(TrackballMessage::first(group, pitch, yaw, *yaw_axis));
+							let yaw_axis = *yaw_axis;
+							if let Some((pitch, yaw)) =
+								controller
+									.snap
+									.first(pitch, yaw, controller.input.first_snap)
+							{
+								trackball_events
+									.write(TrackballMessage::first(group, pitch, yaw, yaw_axis));
+							}
 						}
-					} else if num == 1 {
-						if let Some(rot) = controller.orbit.compute(&pos, &max) {
-							let rot = rot.powf(controller.input.orbit_touch_transmission);
+					} else if num == 1 && controller.mode.orbits() {
+						if let Some((rot, spin)) = controller.drag(
+							&trackball.frame,
+							pos,
+							max,
+							controller.input.orbit_touch_transmission,
+						) && !controller.tap.pending(pos)
+							&& let Some(rot) =
+								controller
+									.snap
+									.orbit(rot, &yaw_axis, controller.input.orbit_snap)
+						{
+							if !controller.snap.enabled() {
+								controller.inertia.orbit(&spin, &Point3::origin());
+							}
 							trackball_events.write(TrackballMessage::orbit(
 								group,
 								rot,
@@ -61,18 +102,36 @@ pub fn touch(
 						{
 							let vec = vec.scale(upp).push(0.0)
 								* controller.input.slide_touch_transmission;
+							controller.inertia.slide(&vec);
 							trackball_events.write(TrackballMessage::slide(group, vec));
 						}
 						if num == 2 {
 							let (pos, _max) = Image::transform_pos_and_max_wrt_max(&pos, &max);
 							let pos = pos.coords.scale(upp).push(0.0);
-							let rot = UnitQuaternion::from_axis_angle(
-								&trackball.frame.local_roll_axis(),
-								rot * controller.input.screw_touch_transmission,
-							);
+							let rot = controller
+								.snap
+								.screw(
+									rot * controller.input.screw_touch_transmission,
+									controller.input.screw_snap,
+								)
+								.map(|ang| {
+									UnitQuaternion::from_axis_angle(
+										&trackball.frame.local_roll_axis(),
+										ang,
+									)
+								});
 							let rat = (1.0 - rat)
 								.mul_add(-controller.input.scale_touch_transmission, 1.0);
-							trackball_events.write(TrackballMessage::orbit(group, rot, pos.into()));
+							controller.inertia.scale(rat);
+							if controller.mode.twists()
+								&& let Some(rot) = rot
+							{
+								trackball_events.write(TrackballMessage::orbit(
+									group,
+									rot,
+									pos.into(),
+								));
+							}
 							trackball_events.write(TrackballMessage::scale(group, rat, pos.into()));
 						}
 					}
@@ -84,7 +143,13 @@ pub fn touch(
 				{
 					let (pos, _max) = Image::transform_pos_and_max_wrt_max(&pos, &max);
 					let vec = pos.coords.scale(upp).push(0.0);
-					trackball_events.write(TrackballMessage::slide(group, vec));
+					if let Some(vec) = controller.tap.defer(vec) {
+						trackball_events.write(TrackballMessage::slide(group, vec));
+					}
+				}
+				if controller.touch.fingers() == 0 {
+					controller.inertia.release();
+					controller.tap.release();
 				}
 				controller.orbit.discard();
 				controller.slide.discard();
@@ -85,36 +85,28 @@ impl TrackballViewport {
 			self.stolen -= 1;
 		}
 	}
+	pub(super) const fn entity(&self) -> Option<Entity> {
+		self.entity
+	}
+	/// Focuses viewport under the cursor or touch and returns whether the focus has changed.
 	#[allow(clippy::too_many_arguments)]
 	#[allow(clippy::type_complexity)]
-	pub(super) fn select<'a>(
+	pub(super) fn focus(
 		viewport: &mut ResMut<Self>,
 		key_input: &Res<ButtonInput<KeyCode>>,
 		mouse_input: &Res<ButtonInput<MouseButton>>,
 		touch_events: &mut MessageReader<TouchInput>,
 		wheel_events: &MessageReader<MouseWheel>,
-		primary_windows: &'a mut Query<
-			(Entity, &mut Window, &mut CursorOptions),
-			With<PrimaryWindow>,
-		>,
-		secondary_windows: &'a mut Query<(&mut Window, &mut CursorOptions), Without<PrimaryWindow>>,
-		cameras: &'a mut Query<(
+		primary_windows: &Query<(Entity, &mut Window, &mut CursorOptions), With<PrimaryWindow>>,
+		secondary_windows: &Query<(&mut Window, &mut CursorOptions), Without<PrimaryWindow>>,
+		cameras: &Query<(
 			Entity,
 			&Camera,
 			&RenderTarget,
 			&TrackballCamera,
 			&mut TrackballController,
 		)>,
-	) -> Option<(
-		bool,
-		Entity,
-		Mut<'a, Window>,
-		Mut<'a, CursorOptions>,
-		Entity,
-		&'a Camera,
-		&'a TrackballCamera,
-		Mut<'a, TrackballController>,
-	)> {
+	) -> bool {
 		let touch = touch_events
 			.read()
 			.filter_map(|touch| (touch.phase == TouchPhase::Started).then_some(touch.position))
@@ -160,6 +152,35 @@ impl TrackballViewport {
 		if is_changed {
 			viewport.entity = new_viewport.entity;
 		}
+		is_changed
+	}
+	/// Selects focused viewport with its window and controller.
+	#[allow(clippy::type_complexity)]
+	pub(super) fn select<'a>(
+		viewport: &mut ResMut<Self>,
+		is_changed: bool,
+		primary_windows: &'a mut Query<
+			(Entity, &mut Window, &mut CursorOptions),
+			With<PrimaryWindow>,
+		>,
+		secondary_windows: &'a mut Query<(&mut Window, &mut CursorOptions), Without<PrimaryWindow>>,
+		cameras: &'a mut Query<(
+			Entity,
+			&Camera,
+			&RenderTarget,
+			&TrackballCamera,
+			&mut TrackballController,
+		)>,
+	) -> Option<(
+		bool,
+		Entity,
+		Mut<'a, Window>,
+		Mut<'a, CursorOptions>,
+		Entity,
+		&'a Camera,
+		&'a TrackballCamera,
+		Mut<'a, TrackballController>,
+	)> {
 		let camera = viewport
 			.entity
 			.and_then(|entity| cameras.get_mut(entity).ok());
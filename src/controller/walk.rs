@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use trackball::nalgebra::Vector3;
+
+use super::{Actions, TrackballAction, TrackballCamera, TrackballController, TrackballMessage};
+use crate::TrackballMode;
+
+#[allow(clippy::too_many_arguments)]
+pub fn walk(
+	group: Entity,
+	trackball_events: &mut MessageWriter<TrackballMessage>,
+	trackball: &TrackballCamera,
+	controller: &mut TrackballController,
+	actions: &Actions,
+	v: f32,
+	t: f32,
+) {
+	let TrackballMode::Walk {
+		ref ground,
+		height,
+		jump,
+		gravity,
+		..
+	} = controller.mode
+	else {
+		controller.fall = 0.0;
+		controller.grounded = false;
+		return;
+	};
+	let Some(&up) = controller.first.yaw_axis() else {
+		return;
+	};
+	let ground = ground.clone();
+	let rot = trackball.frame.view().rotation;
+	let mut vec = Vector3::zeros();
+	for (action, dir) in [
+		(TrackballAction::SlideFar, -Vector3::z()),
+		(TrackballAction::SlideNear, Vector3::z()),
+		(TrackballAction::SlideLeft, -Vector3::x()),
+		(TrackballAction::SlideRight, Vector3::x()),
+	] {
+		if actions.pressed(action) {
+			vec += rot * dir;
+		}
+	}
+	let speed = controller
+		.mode
+		.speed(actions.pressed(TrackballAction::Boost));
+	let v = v * controller.input.slide_key_transmission * speed;
+	let vec = (vec - up.into_inner() * up.dot(&vec))
+		.try_normalize(f32::EPSILON)
+		.map_or_else(Vector3::zeros, |vec| vec * v * t);
+	if actions.just_pressed(TrackballAction::SlideUp) && controller.grounded && jump > 0.0 {
+		controller.fall = jump;
+		controller.grounded = false;
+	}
+	let eye = Vec3::from(trackball.frame.eye() + vec);
+	let up_dir = Dir3::new_unchecked(Vec3::from(up.into_inner()));
+	let rise = if let Some(distance) = ground.distance(eye + up_dir * height, -up_dir) {
+		let elevation = distance - height;
+		controller.fall -= gravity * t;
+		let rise = controller.fall * t;
+		if elevation + rise <= height {
+			controller.fall = 0.0;
+			controller.grounded = true;
+			height - elevation
+		} else {
+			controller.grounded = false;
+			rise
+		}
+	} else {
+		controller.fall = 0.0;
+		controller.grounded = false;
+		0.0
+	};
+	let user = vec.norm_squared() > 0.0;
+	let vec = vec + up.into_inner() * rise;
+	if user {
+		trackball_events.write(TrackballMessage::slide(group, rot.inverse() * vec));
+	} else if rise.abs() > f32::EPSILON.sqrt() {
+		trackball_events.write(TrackballMessage::slide(group, rot.inverse() * vec).automatic());
+	}
+}
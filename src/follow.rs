@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+use trackball::nalgebra::{Isometry3, Point3, Translation3, UnitQuaternion, Vector3};
+
+use super::TrackballCamera;
+
+/// Follow component moving [`TrackballCamera`] along with another entity.
+///
+/// Moves the [`Frame`] of the camera rigidly along with the [`GlobalTransform`] of the followed
+/// entity (e.g., a moving vehicle or an animated character). The offsets of target and eye with
+/// respect to the followed entity are preserved, so orbiting, scaling, and sliding by user input
+/// keep working as usual. Optionally, the camera inherits the rotation of the followed entity.
+///
+/// The motion of the followed entity is smoothed by [`Self::smooth`] separately from
+/// [`TrackballCamera::blend`] which keeps smoothing user input only. Following moves the reset
+/// frame and flights along but bypasses [`TrackballCamera::clamp`]. Changing [`Self::entity`]
+/// moves the camera over to the new entity preserving the current offsets.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_trackball::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+/// 	let vehicle = commands.spawn(Transform::default()).id();
+/// 	let [target, eye, up] = [Vec3::ZERO, Vec3::Z * 10.0, Vec3::Y];
+/// 	commands.spawn((
+/// 		TrackballController::default(),
+/// 		TrackballCamera::look_at(target, eye, up),
+/// 		TrackballFollow::new(vehicle)
+/// 			.with_rotation(true)
+/// 			.with_smooth(100.0),
+/// 		Camera3d::default(),
+/// 	));
+/// }
+/// ```
+///
+/// [`Frame`]: trackball::Frame
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct TrackballFollow {
+	/// Followed entity with [`GlobalTransform`].
+	pub entity: Entity,
+	/// Whether to inherit the rotation of the followed entity. Default is `false`.
+	pub rotation: bool,
+	/// Smoothing half-life from 0 (rigid) to 1000 (slow) milliseconds. Default is `0.0`.
+	///
+	/// It is the time passed until halfway of fps-agnostic exponential ease-out.
+	pub smooth: f32,
+	anchor: Option<Isometry3<f32>>,
+}
+
+impl TrackballFollow {
+	/// Follows `entity` rigidly without inheriting its rotation.
+	#[must_use]
+	pub const fn new(entity: Entity) -> Self {
+		Self {
+			entity,
+			rotation: false,
+			smooth: 0.0,
+			anchor: None,
+		}
+	}
+	/// Defines whether to inherit rotation, see [`Self::rotation`].
+	#[must_use]
+	pub const fn with_rotation(mut self, rotation: bool) -> Self {
+		self.rotation = rotation;
+		self
+	}
+	/// Defines smoothing half-life, see [`Self::smooth`].
+	#[must_use]
+	pub const fn with_smooth(mut self, smooth: f32) -> Self {
+		self.smooth = smooth;
+		self
+	}
+}
+
+#[allow(clippy::needless_pass_by_value)]
+pub fn trackball_follow(
+	time: Res<Time>,
+	mut cameras: Query<(&mut TrackballCamera, &mut TrackballFollow)>,
+	transforms: Query<&GlobalTransform>,
+) {
+	for (mut trackball, mut follow) in &mut cameras {
+		let Ok(transform) = transforms.get(follow.entity) else {
+			continue;
+		};
+		let (_scale, rotation, translation) = transform.to_scale_rotation_translation();
+		let pose = Isometry3::from_parts(
+			Translation3::from(Vector3::from(translation)),
+			UnitQuaternion::from(rotation),
+		);
+		let Some(old_pose) = follow.anchor else {
+			follow.anchor = Some(pose);
+			continue;
+		};
+		let smooth = (follow.smooth * 1e-3).clamp(0.0, 1.0);
+		let blend = if smooth > 0.0 {
+			1.0 - 0.5f32.powf(time.delta_secs() / smooth)
+		} else {
+			1.0
+		};
+		let new_pose = old_pose
+			.try_lerp_slerp(&pose, blend, f32::EPSILON)
+			.unwrap_or(pose);
+		if new_pose == old_pose {
+			continue;
+		}
+		follow.anchor = Some(new_pose);
+		let vec = new_pose.translation.vector - old_pose.translation.vector;
+		let rot = if follow.rotation {
+			new_pose.rotation * old_pose.rotation.inverse()
+		} else {
+			UnitQuaternion::identity()
+		};
+		let pos = Point3::from(new_pose.translation.vector);
+		trackball.follow(&vec, &rot, &pos);
+	}
+}
@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use trackball::{Clamp, Frame, Plane, nalgebra};
+
+use super::{TrackballCamera, TrackballClampMessage, TrackballSystemSet, clamps::Limit};
+
+/// Plugin drawing [`TrackballCamera`] components with [`Gizmos`] for debugging.
+///
+/// Draws cameras with [`TrackballGizmo`] component or all cameras if
+/// [`TrackballGizmoConfigGroup::draw_all`] is set. Rendering gizmos requires the
+/// `bevy_gizmos_render` feature of `bevy`.
+#[derive(Default)]
+pub struct TrackballGizmoPlugin;
+
+impl Plugin for TrackballGizmoPlugin {
+	fn build(&self, app: &mut App) {
+		app.init_gizmo_group::<TrackballGizmoConfigGroup>()
+			.add_systems(Update, trackball_gizmo.after(TrackballSystemSet::Camera));
+	}
+}
+
+/// The [`GizmoConfigGroup`] used for debug visualizations of [`TrackballCamera`] components.
+#[derive(Debug, Clone, Reflect, GizmoConfigGroup)]
+#[reflect(Clone, Default)]
+pub struct TrackballGizmoConfigGroup {
+	/// Draws all cameras as if they had a default [`TrackballGizmo`] component. Default is `false`.
+	pub draw_all: bool,
+	/// Color of trackball circle, target position, and up axis.
+	pub trackball_color: Color,
+	/// Color of focus plane.
+	pub focus_color: Color,
+	/// Color of active boundary planes of [`TrackballCamera::clamp`].
+	pub clamp_color: Color,
+	/// Color of configured limits of [`TrackballCamera::clamp`], see [`TrackballCamera::limits`].
+	pub limit_color: Color,
+}
+
+impl Default for TrackballGizmoConfigGroup {
+	fn default() -> Self {
+		Self {
+			draw_all: false,
+			trackball_color: Color::srgb(1.0, 0.8, 0.0),
+			focus_color: Color::srgba(0.0, 0.8, 1.0, 0.5),
+			clamp_color: Color::srgb(1.0, 0.2, 0.2),
+			limit_color: Color::srgba(1.0, 0.2, 0.2, 0.3),
+		}
+	}
+}
+
+/// Toggles debug visualizations of [`TrackballCamera`] component, see [`TrackballGizmoPlugin`].
+///
+/// Everything is drawn by default.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct TrackballGizmo {
+	/// Whether to draw the trackball circle at target position.
+	///
+	/// Its radius is the minimum half extent of the viewport at target distance.
+	pub trackball: bool,
+	/// Whether to draw the target position and the up axis.
+	pub target: bool,
+	/// Whether to draw the focus plane through target position.
+	pub focus: bool,
+	/// Whether to draw the active boundary planes of [`TrackballCamera::clamp`].
+	///
+	/// They are highlighted on top of [`Self::limits`] while the camera is clamped.
+	pub clamp: bool,
+	/// Whether to draw the configured limits of [`TrackballCamera::clamp`].
+	///
+	/// Only limits defined via [`TrackballCamera::with_limits`] are known.
+	pub limits: bool,
+}
+
+impl Default for TrackballGizmo {
+	fn default() -> Self {
+		Self {
+			trackball: true,
+			target: true,
+			focus: true,
+			clamp: true,
+			limits: true,
+		}
+	}
+}
+
+#[allow(clippy::needless_pass_by_value)]
+#[allow(clippy::type_complexity)]
+fn trackball_gizmo(
+	mut gizmos: Gizmos<TrackballGizmoConfigGroup>,
+	cameras: Query<(Entity, &TrackballCamera, Option<&TrackballGizmo>)>,
+	mut clamp_events: MessageReader<TrackballClampMessage>,
+	mut planes: Local<HashMap<Entity, Vec<(Plane<f32>, Vec3)>>>,
+) {
+	for clamp_event in clamp_events.read() {
+		let Ok((_entity, trackball, _gizmo)) = cameras.get(clamp_event.camera) else {
+			continue;
+		};
+		if let Some(clamp) = &trackball.clamp
+			&& clamp_event.clamped.is_some()
+		{
+			let frame = clamp_event.delta.transform(&clamp_event.frame);
+			planes.insert(clamp_event.camera, active_planes(clamp.as_ref(), &frame));
+		} else {
+			planes.remove(&clamp_event.camera);
+		}
+	}
+	let config = gizmos.config_ext.clone();
+	for (entity, trackball, gizmo) in &cameras {
+		let Some(gizmo) = gizmo
+			.copied()
+			.or_else(|| config.draw_all.then(TrackballGizmo::default))
+		else {
+			continue;
+		};
+		let frame = &trackball.frame;
+		let (max, _upp) = trackball
+			.scope
+			.fov()
+			.max_and_upp(frame.distance(), &trackball.old_max);
+		let radius = max.x.min(max.y);
+		let target = Vec3::from(*frame.target());
+		let rotation = Quat::from(frame.view().rotation);
+		if gizmo.trackball {
+			gizmos.circle(
+				Isometry3d::new(target, rotation),
+				radius,
+				config.trackball_color,
+			);
+		}
+		if gizmo.target {
+			let up = Vec3::from(frame.yaw_axis().into_inner());
+			gizmos.sphere(target, radius * 0.02, config.trackball_color);
+			gizmos.arrow(target, target + up * radius * 0.5, config.trackball_color);
+		}
+		if gizmo.focus {
+			let size = Vec2::new(max.x, max.y) * 2.0;
+			gizmos.rect(Isometry3d::new(target, rotation), size, config.focus_color);
+		}
+		if gizmo.limits {
+			for limit in &trackball.limits {
+				draw_limit(&mut gizmos, limit, frame, radius, config.limit_color);
+			}
+		}
+		if gizmo.clamp
+			&& trackball.is_clamped()
+			&& let Some(planes) = planes.get(&entity)
+		{
+			for &(plane, point) in planes {
+				let normal = Vec3::from(plane.normal.into_inner());
+				let rotation = Quat::from_rotation_arc(Vec3::Z, normal);
+				let isometry = Isometry3d::new(point, rotation);
+				gizmos.grid(
+					isometry,
+					UVec2::splat(8),
+					Vec2::splat(radius * 0.25),
+					config.clamp_color,
+				);
+				gizmos.arrow(point, point + normal * radius * 0.25, config.clamp_color);
+			}
+		}
+	}
+}
+
+/// Active boundary planes of `clamp` for `frame` with their points closest to target or eye.
+fn active_planes(clamp: &dyn Clamp<f32>, frame: &Frame<f32>) -> Vec<(Plane<f32>, Vec3)> {
+	let target = frame.target();
+	let eye = frame.eye();
+	[
+		clamp
+			.target(frame)
+			.map(|plane| (plane, plane.project_point(target))),
+		clamp
+			.eye(frame)
+			.map(|plane| (plane, plane.project_point(&eye))),
+		clamp.up(frame).map(|plane| (plane, *target)),
+	]
+	.into_iter()
+	.flatten()
+	.map(|(plane, point)| (plane, point.into()))
+	.collect()
+}
+
+/// Draws configured `limit` relative to target position of `frame`.
+fn draw_limit(
+	gizmos: &mut Gizmos<TrackballGizmoConfigGroup>,
+	limit: &Limit,
+	frame: &Frame<f32>,
+	radius: f32,
+	color: Color,
+) {
+	let target = Vec3::from(*frame.target());
+	match *limit {
+		Limit::Box {
+			transform,
+			min,
+			max,
+		} => {
+			let center = transform * nalgebra::center(&min, &max);
+			let transform = Transform::from_translation(Vec3::from(center))
+				.with_rotation(Quat::from(transform.rotation))
+				.with_scale(Vec3::from(max - min));
+			gizmos.cube(transform, color);
+		}
+		Limit::Sphere { center, radius } => {
+			gizmos.sphere(Vec3::from(center), radius, color);
+		}
+		Limit::Plane(plane) => {
+			let normal = Vec3::from(plane.normal.into_inner());
+			let point = Vec3::from(plane.project_point(frame.target()));
+			let rotation = Quat::from_rotation_arc(Vec3::Z, normal);
+			gizmos.grid(
+				Isometry3d::new(point, rotation),
+				UVec2::splat(8),
+				Vec2::splat(radius * 0.25),
+				color,
+			);
+		}
+		Limit::Distance { min, max } => {
+			for distance in [min, max] {
+				if distance > 0.0 && distance < f32::MAX {
+					gizmos.sphere(target, distance, color);
+				}
+			}
+		}
+		Limit::Pitch { up, min, max } => {
+			let up = Vec3::from(up.into_inner());
+			let rotation = Quat::from_rotation_arc(Vec3::Z, up);
+			let distance = frame.distance();
+			for angle in [min, max] {
+				let (sin, cos) = angle.sin_cos();
+				let center = target + up * distance * sin;
+				gizmos.circle(Isometry3d::new(center, rotation), distance * cos, color);
+			}
+		}
+	}
+}
@@ -13,6 +13,14 @@
 //! Supports multiple camera modes:
 //!
 //!   * Trackball mode rotates camera around target.
+//!   * Turntable mode rotates camera around target about a fixed up axis without rolling, see
+//!     [`TrackballMode::Turntable`].
+//!   * Fly mode permanently looks around by mouse and slides relative to the view by keys, see
+//!     [`TrackballMode::Fly`].
+//!   * Walk mode looks around like fly mode but slides horizontally, keeps the eye height above
+//!     ground, and applies gravity, see [`TrackballMode::Walk`].
+//!   * Map mode pans and zooms an orthographic top-down view without orbiting, optionally rotating
+//!     about the view axis by twisting two fingers, see [`TrackballMode::Map`].
 //!   * First-person mode rotates target around camera.
 //!   * Spectator mode translates target and camera.
 //!
@@ -52,12 +60,28 @@
 //!   * Time-free multi-touch gesture recognition for orbit, scale, slide, and focus (i.e., slide to
 //!     cursor/finger position) operations.
 //!   * Smoothing of movement implemented as fps-agnostic exponential ease-out.
+//!   * Optional kinetic inertia keeping the camera moving after releasing a drag operation with
+//!     fps-agnostic exponential friction.
+//!   * Animated fly-to transitions interpolating target, rotation, and distance with selectable
+//!     easing functions, canceled as soon as the user grabs the camera, see
+//!     [`TrackballCamera::fly_to`].
+//!   * Zoom-to-fit framing axis-aligned bounding boxes, bounding spheres, or mesh bounds of
+//!     entities with respect to field of view mode, projection mode, and viewport aspect ratio,
+//!     see [`TrackballMessage::frame_bounds`].
+//!   * Camera bookmarks storing and recalling named camera states with animated transitions,
+//!     optionally serializable to share viewpoints, see [`TrackballBookmarks`].
 //!   * Gimbal lock-free using quaternion instead of Euler angles.
 //!   * Gliding clamp (experimental): The movement of a camera can be restricted to user-defined
 //!     boundary conditions (e.g., to not orbit below the ground plane). When the movement is not
 //!     orthogonal to a boundary plane, it is changed such that the camera glides along the boundary
-//!     plane. Currently, only implemented for orbit and slide operations, see the [`gliding_clamp`]
-//!     example.
+//!     plane. This applies to orbit, slide, scale, and first-person operations, see the
+//!     [`gliding_clamp`] example. Ready-made and composable boundary conditions (e.g., terrain or
+//!     mesh collision) are provided by the [`clamps`] module. Clamps becoming active or releasing
+//!     are reported via [`TrackballClampMessage`] (e.g., to play a bump sound). Optionally, the
+//!     camera overshoots boundary planes and springs back once input has ended, see
+//!     [`clamps::Elastic`].
+//!   * Following moving entities (e.g., vehicles or animated characters) with optionally inherited
+//!     rotation and separate smoothing while preserving user input, see [`TrackballFollow`].
 //!   * Camera constellation: A camera is decoupled from its input controller and instead multiple
 //!     cameras can be sensitive to zero or multiple selected controllers (e.g., a minimap
 //!     controlled by the same controller of the main viewport).
@@ -84,20 +108,25 @@
 //! Following features are disabled unless their corresponding feature gate is enabled:
 //!
 //!   * `bevy_egui` for automatic viewport stealing whenever `egui` wants focus.
+//!   * `bevy_picking` for picking surface points under the cursor (e.g., orbiting or zooming
+//!     around them).
+//!   * `bevy_gizmos` for drawing trackball, focus plane, clamp limits, and active boundary planes
+//!     for debugging, see `TrackballGizmoPlugin`.
+//!   * `bevy_pbr` for rendering an interactive orientation cube in a corner of the viewport which
+//!     orbits the camera to a clicked face, edge, or corner, see `TrackballViewCubePlugin`.
 //!   * `serialize` for `serde` support of various structures of this crate and its dependencies.
 //!   * `c11-orbit` for testing the behaviorally identical C implementation of the exponential map.
 //!
 //! # Roadmap
 //!
-//!   * Implement gliding clamp for first-person mode and scale operation, see
-//!     [issue](https://github.com/qu1x/bevy_trackball/issues/5).
 //!   * Support more camera modes out of the box by adding dedicated controllers for each mode, see
 //!     [issue](https://github.com/qu1x/bevy_trackball/issues/3).
-//!   * Support gamepad inputs, see [issue](https://github.com/qu1x/bevy_trackball/issues/4).
 //!
 //! # Input Mappings
 //!
-//! Following mappings are the defaults which can be customized, see [`TrackballInput`].
+//! Following mappings are the defaults which can be customized, see [`TrackballBindings`]. Each
+//! [`TrackballAction`] can be bound to multiple keys, mouse buttons, gamepad buttons, and modifier
+//! chords thereof (e.g., `Ctrl` + left mouse button to slide).
 //!
 //! Mouse (Buttons)         | Touch (Fingers)         | Keyboard | Operation
 //! ----------------------- | ----------------------- | -------- | ---------------------------------
@@ -108,13 +137,38 @@
 //! &nbsp;                  | &nbsp;                  | `gv`     | Slides trackball in/out.
 //! Scroll In/Out           | Two + Pinch Out/In      | `hn`     | Scales distance zooming in/out.
 //! Left Press + Release    | Any + Release           | &nbsp;   | Slides to cursor/finger position.
+//! Left Double Click       | One + Double Tap        | &nbsp;   | Flies to picked point if enabled.
 //! &nbsp;                  | &nbsp;                  | `m`      | Toggle `esdf`/`wasd` mapping.
 //! &nbsp;                  | &nbsp;                  | `p`      | Toggle orthographic/perspective.
 //! &nbsp;                  | &nbsp;                  | `Enter`  | Reset camera transform.
 //!
+//! Standard views are mapped to the numpad where `1`, `3`, and `7` animate to the front, right, and
+//! top view, `Ctrl` + `1`, `3`, and `7` to the back, left, and bottom view, and `0` to the
+//! isometric view, see [`StandardView`].
+//!
+//! Bookmarks are mapped to the digit row where `Ctrl` + `1` to `9` store and `1` to `9` recall the
+//! camera state with an animated transition, see [`TrackballBookmarks`].
+//!
+//! Holding `AltLeft` snaps orbiting, rolling, and looking around to steps of 15°, see
+//! [`TrackballAction::Snap`] and [`TrackballInput::orbit_snap`].
+//!
 //! Alternatively, [`TrackballInput::map_wasd`] maps `wasd`/`Space`/`ControlLeft` to slide
 //! operations where `ws` slides in/out and `Space`/`ControlLeft` slides up/down (jump/crouch).
 //!
+//! Fly mode is mapped by [`TrackballController::map_fly`] where `wasd`/`qe` slide relative to the
+//! view, `ShiftLeft` boosts, and scrolling adjusts the slide speed.
+//!
+//! Walk mode is mapped by [`TrackballController::map_walk`] where `wasd` walk, `Space` jumps,
+//! `ShiftLeft` boosts, and scrolling adjusts the walk speed.
+//!
+//! Mouse navigation of common 3D applications is mapped by [`TrackballInput::map_blender`],
+//! [`TrackballInput::map_maya`], and [`TrackballInput::map_cad`] using modifier chords and
+//! [`TrackballAction::Scale`] to scale by mouse drag.
+//!
+//! Gamepad sticks and triggers are mapped as well where the left stick orbits (or looks around
+//! while holding the left shoulder button), the right stick slides, and the right/left trigger
+//! scales in/out at the trackball velocity times deflection beyond a dead zone.
+//!
 //! # Usage
 //!
 //! Add the [`TrackballPlugin`] followed by spawning a [`TrackballController`] together with a
@@ -153,24 +207,40 @@
 //! [`egui`]: https://qu1x.dev/bevy_trackball/egui.html
 //! [`scaling_modes`]: https://github.com/qu1x/bevy_trackball/blob/main/examples/scaling_modes.rs
 
-use bevy::prelude::*;
+use bevy::{
+	camera::primitives::Aabb,
+	math::bounding::{Aabb3d, BoundingSphere, BoundingVolume},
+	prelude::*,
+};
+pub use bookmarks::{TrackballBookmark, TrackballBookmarks};
 pub use camera::TrackballCamera;
 use camera::trackball_camera;
 use constellation::trackball_constellation;
 use controller::trackball_controller;
 pub use controller::{
-	TrackballController, TrackballInput, TrackballVelocity, TrackballViewport, TrackballWheelUnit,
+	TrackballAction, TrackballBinding, TrackballBindings, TrackballButton, TrackballController,
+	TrackballGround, TrackballInput, TrackballMode, TrackballModifiers, TrackballVelocity,
+	TrackballViewport, TrackballWheelUnit,
 };
+pub use follow::TrackballFollow;
+use follow::trackball_follow;
+#[cfg(feature = "bevy_gizmos")]
+pub use gizmo::{TrackballGizmo, TrackballGizmoConfigGroup, TrackballGizmoPlugin};
 pub use trackball;
 use trackball::{
-	Delta,
+	Delta, Frame,
 	nalgebra::{Point3, Unit, UnitQuaternion, Vector3},
 };
+#[cfg(feature = "bevy_pbr")]
+pub use view_cube::{TrackballViewCube, TrackballViewCubePlugin};
 
 /// Prelude to get started quickly.
 pub mod prelude {
 	pub use super::{
-		TrackballCamera, TrackballController, TrackballInput, TrackballMessage, TrackballPlugin,
+		StandardView, TrackballAction, TrackballBinding, TrackballBindings, TrackballBookmark,
+		TrackballBookmarks, TrackballButton, TrackballCamera, TrackballClampMessage,
+		TrackballController, TrackballFlightMessage, TrackballFollow, TrackballGround,
+		TrackballInput, TrackballMessage, TrackballMode, TrackballModifiers, TrackballPlugin,
 		TrackballSetup, TrackballSystemSet, TrackballVelocity, TrackballViewport,
 		TrackballWheelUnit,
 		trackball::{
@@ -183,10 +253,21 @@ pub mod prelude {
 			nalgebra::{Isometry3, Point3, Unit, UnitQuaternion, Vector3},
 		},
 	};
+	#[cfg(feature = "bevy_gizmos")]
+	pub use super::{TrackballGizmo, TrackballGizmoPlugin};
+	#[cfg(feature = "bevy_pbr")]
+	pub use super::{TrackballViewCube, TrackballViewCubePlugin};
 }
+mod bookmarks;
 mod camera;
+pub mod clamps;
 mod constellation;
 mod controller;
+mod follow;
+#[cfg(feature = "bevy_gizmos")]
+mod gizmo;
+#[cfg(feature = "bevy_pbr")]
+mod view_cube;
 
 /// Plugin adding and configuring systems and their resources.
 ///
@@ -211,9 +292,20 @@ pub struct TrackballMessage {
 	pub delta: Delta<f32>,
 	/// Setup of [`TrackballCamera`].
 	pub setup: Option<TrackballSetup>,
+	/// Whether this event originates from user input and hence cancels any flight of
+	/// [`TrackballCamera`], see [`TrackballCamera::fly_to`]. Default of all constructors is
+	/// `true`, see [`Self::automatic`].
+	pub user: bool,
 }
 
 impl TrackballMessage {
+	/// Marks event as not originating from user input, see [`Self::user`].
+	#[must_use]
+	#[inline]
+	pub const fn automatic(mut self) -> Self {
+		self.user = false;
+		self
+	}
 	/// Creates [`Delta::First`] event for camera `group`.
 	#[must_use]
 	#[inline]
@@ -226,6 +318,7 @@ impl TrackballMessage {
 				yaw_axis,
 			},
 			setup: None,
+			user: true,
 		}
 	}
 	/// Creates [`Delta::Track`] event for camera `group`.
@@ -236,6 +329,7 @@ impl TrackballMessage {
 			group,
 			delta: Delta::Track { vec },
 			setup: None,
+			user: true,
 		}
 	}
 	/// Creates [`Delta::Orbit`] event for camera `group`.
@@ -246,6 +340,7 @@ impl TrackballMessage {
 			group,
 			delta: Delta::Orbit { rot, pos },
 			setup: None,
+			user: true,
 		}
 	}
 	/// Creates [`Delta::Slide`] event for camera `group`.
@@ -256,6 +351,7 @@ impl TrackballMessage {
 			group,
 			delta: Delta::Slide { vec },
 			setup: None,
+			user: true,
 		}
 	}
 	/// Creates [`Delta::Scale`] event for camera `group`.
@@ -266,6 +362,7 @@ impl TrackballMessage {
 			group,
 			delta: Delta::Scale { rat, pos },
 			setup: None,
+			user: true,
 		}
 	}
 	/// Creates [`TrackballSetup::Reset`] event for camera `group`.
@@ -276,6 +373,7 @@ impl TrackballMessage {
 			group,
 			delta: Delta::Frame,
 			setup: Some(TrackballSetup::Reset),
+			user: true,
 		}
 	}
 	/// Creates [`TrackballSetup::Ortho`] event for camera `group`.
@@ -286,8 +384,100 @@ impl TrackballMessage {
 			group,
 			delta: Delta::Frame,
 			setup: Some(TrackballSetup::Ortho(ortho)),
+			user: true,
+		}
+	}
+	/// Creates [`TrackballSetup::Target`] event for camera `group`.
+	#[must_use]
+	#[inline]
+	pub const fn target(group: Entity, pos: Vec3, distance: bool, duration: f32) -> Self {
+		Self {
+			group,
+			delta: Delta::Frame,
+			setup: Some(TrackballSetup::Target {
+				pos,
+				distance,
+				duration,
+			}),
+			user: true,
+		}
+	}
+	/// Creates [`TrackballSetup::View`] event for camera `group`.
+	#[must_use]
+	#[inline]
+	pub const fn view(group: Entity, view: StandardView) -> Self {
+		Self {
+			group,
+			delta: Delta::Frame,
+			setup: Some(TrackballSetup::View(view)),
+			user: true,
+		}
+	}
+	/// Creates [`TrackballSetup::Store`] event for camera `group`.
+	#[must_use]
+	#[inline]
+	pub const fn store(group: Entity, slot: u8) -> Self {
+		Self {
+			group,
+			delta: Delta::Frame,
+			setup: Some(TrackballSetup::Store(slot)),
+			user: true,
+		}
+	}
+	/// Creates [`TrackballSetup::Recall`] event for camera `group`.
+	#[must_use]
+	#[inline]
+	pub const fn recall(group: Entity, slot: u8) -> Self {
+		Self {
+			group,
+			delta: Delta::Frame,
+			setup: Some(TrackballSetup::Recall(slot)),
+			user: true,
+		}
+	}
+	/// Creates [`TrackballSetup::Bounds`] event for camera `group`.
+	#[must_use]
+	#[inline]
+	pub const fn frame_bounds(group: Entity, aabb: Aabb3d, margin: f32) -> Self {
+		Self {
+			group,
+			delta: Delta::Frame,
+			setup: Some(TrackballSetup::Bounds { aabb, margin }),
+			user: true,
 		}
 	}
+	/// Creates [`TrackballSetup::Sphere`] event for camera `group`.
+	#[must_use]
+	#[inline]
+	pub const fn frame_sphere(group: Entity, sphere: BoundingSphere, margin: f32) -> Self {
+		Self {
+			group,
+			delta: Delta::Frame,
+			setup: Some(TrackballSetup::Sphere { sphere, margin }),
+			user: true,
+		}
+	}
+	/// Creates [`TrackballSetup::Bounds`] event for camera `group` framing entities.
+	///
+	/// Merges the mesh bounds of entities transformed into world space. Returns `None` if there
+	/// are no `bounds`.
+	#[must_use]
+	pub fn frame_entities<'a>(
+		group: Entity,
+		bounds: impl IntoIterator<Item = (&'a Aabb, &'a GlobalTransform)>,
+		margin: f32,
+	) -> Option<Self> {
+		bounds
+			.into_iter()
+			.map(|(aabb, transform)| {
+				let affine = transform.affine();
+				let center = affine.transform_point3a(aabb.center);
+				let half_size = affine.matrix3.abs() * aabb.half_extents;
+				Aabb3d::new(center, half_size)
+			})
+			.reduce(|aabb, other| aabb.merge(&other))
+			.map(|aabb| Self::frame_bounds(group, aabb, margin))
+	}
 
 	/// Applies `transmission` ratio.
 	#[must_use]
@@ -298,8 +488,44 @@ impl TrackballMessage {
 	}
 }
 
+/// Event sent from [`TrackballCamera`] component whenever its flight has ended.
+///
+/// See [`TrackballCamera::fly_to`].
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackballFlightMessage {
+	/// Entity of [`TrackballCamera`] component which has ended its flight.
+	pub camera: Entity,
+	/// Whether the flight has arrived at its final frame or has been canceled.
+	pub arrived: bool,
+}
+
+/// Event sent from [`TrackballCamera`] component whenever its clamp is active or releases.
+///
+/// It is sent for every clamped delta as long as the clamp is active and once more with `clamped`
+/// being `None` when the clamp releases, so the first event with a clamped delta marks the clamp
+/// becoming active (e.g., to play a bump sound or to show a boundary hint). Cameras of a rigid
+/// constellation are clamped together, hence all of them send this event.
+///
+/// See [`TrackballCamera::clamp`] and [`TrackballCamera::is_clamped`].
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct TrackballClampMessage {
+	/// Entity of [`TrackballCamera`] component which has been clamped or released.
+	pub camera: Entity,
+	/// Entity of [`TrackballController`] component which has sent the delta.
+	pub group: Entity,
+	/// Frame of the camera before applying the delta.
+	pub frame: Frame<f32>,
+	/// Original delta as sent by the controller.
+	pub delta: Delta<f32>,
+	/// Clamped delta as applied to the camera or `None` if the clamp has been released.
+	pub clamped: Option<Delta<f32>>,
+	/// Whether the clamped delta differs from the original delta as the clamp or the elastic
+	/// boundary has cut it short or redirected it.
+	pub partial: bool,
+}
+
 /// Setup of [`TrackballCamera`] as part of [`TrackballMessage`].
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 #[non_exhaustive]
 pub enum TrackballSetup {
 	/// Reset camera frame.
@@ -310,6 +536,78 @@ pub enum TrackballSetup {
 	///   * Perspective with `Some(false)`
 	///   * Toggle with `None`
 	Ortho(Option<bool>),
+	/// Fly target to position preserving eye rotation, see [`TrackballCamera::fly_to`].
+	Target {
+		/// Target position in world space.
+		pos: Vec3,
+		/// Whether to adjust the distance to the depth of `pos`, so the eye moves laterally only.
+		distance: bool,
+		/// Flight duration in milliseconds.
+		duration: f32,
+	},
+	/// Frame axis-aligned bounding box, see [`TrackballCamera::fit_aabb`].
+	Bounds {
+		/// Axis-aligned bounding box in world space.
+		aabb: Aabb3d,
+		/// Relative margin (e.g., `0.1` for 10 % on each side).
+		margin: f32,
+	},
+	/// Frame bounding sphere, see [`TrackballCamera::fit_sphere`].
+	Sphere {
+		/// Bounding sphere in world space.
+		sphere: BoundingSphere,
+		/// Relative margin (e.g., `0.1` for 10 % on each side).
+		margin: f32,
+	},
+	/// Animate to standard view, see [`TrackballCamera::frame_view`].
+	View(StandardView),
+	/// Store bookmark slot from `1` to `9`, see [`TrackballBookmarks`].
+	Store(u8),
+	/// Recall bookmark slot from `1` to `9`, see [`TrackballBookmarks`].
+	Recall(u8),
+}
+
+/// Standard view of [`TrackballSetup::View`].
+///
+/// Defined relative to the world basis of [`TrackballCamera::view_up`] and
+/// [`TrackballCamera::view_forward`] where the right direction is their cross product (i.e.,
+/// forward × up). Each view looks at the current target preserving the current distance.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[non_exhaustive]
+pub enum StandardView {
+	/// Looks forward.
+	Front,
+	/// Looks backward.
+	Back,
+	/// Looks right from the left.
+	Left,
+	/// Looks left from the right.
+	Right,
+	/// Looks down with forward pointing up on screen.
+	Top,
+	/// Looks up with backward pointing up on screen.
+	Bottom,
+	/// Looks down forward and left from the front right top corner.
+	Isometric,
+}
+
+impl StandardView {
+	/// Direction from target to eye and up direction on screen with respect to world basis.
+	#[must_use]
+	pub fn direction_and_up(self, up: Dir3, forward: Dir3) -> Option<(Dir3, Dir3)> {
+		let forward = Dir3::new(forward.reject_from_normalized(*up)).ok()?;
+		let right = forward.cross(*up);
+		let (eye, screen_up) = match self {
+			Self::Front => (-*forward, *up),
+			Self::Back => (*forward, *up),
+			Self::Left => (-right, *up),
+			Self::Right => (right, *up),
+			Self::Top => (*up, *forward),
+			Self::Bottom => (-*up, -*forward),
+			Self::Isometric => (right + *up - *forward, *up),
+		};
+		Some((Dir3::new(eye).ok()?, Dir3::new(screen_up).ok()?))
+	}
 }
 
 /// System sets configured by [`TrackballPlugin`].
@@ -337,6 +635,8 @@ impl Plugin for TrackballPlugin {
 	fn build(&self, app: &mut App) {
 		app.init_resource::<TrackballViewport>()
 			.add_message::<TrackballMessage>()
+			.add_message::<TrackballFlightMessage>()
+			.add_message::<TrackballClampMessage>()
 			.add_systems(
 				Update,
 				(
@@ -347,6 +647,10 @@ impl Plugin for TrackballPlugin {
 					trackball_camera.in_set(TrackballSystemSet::Camera),
 				)
 					.chain(),
+			)
+			.add_systems(
+				Update,
+				trackball_follow.before(TrackballSystemSet::Controller),
 			);
 		#[cfg(feature = "bevy_egui")]
 		{
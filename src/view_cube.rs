@@ -0,0 +1,413 @@
+use bevy::{
+	camera::{RenderTarget, ScalingMode, Viewport, visibility::RenderLayers},
+	math::bounding::{Aabb3d, RayCast3d},
+	prelude::*,
+	window::{PrimaryWindow, WindowRef},
+};
+use trackball::{
+	Frame, Orbit,
+	nalgebra::{Point2, Point3, Vector3},
+};
+
+use super::{TrackballCamera, TrackballMessage, TrackballSystemSet, TrackballViewport};
+
+/// Inner half extent of cube pieces separating faces from edges and corners.
+const INNER: f32 = 0.6;
+/// Gap between cube pieces.
+const GAP: f32 = 0.03;
+/// Distance of overlay camera to cube center.
+const DISTANCE: f32 = 4.0;
+/// Maximum cursor movement in logical pixels to be considered a click instead of a drag.
+const CLICK: f32 = 4.0;
+
+/// Plugin rendering [`TrackballViewCube`] components as orientation cubes in overlay cameras.
+///
+/// Rendering requires the `bevy_pbr` feature gate.
+#[derive(Default)]
+pub struct TrackballViewCubePlugin;
+
+impl Plugin for TrackballViewCubePlugin {
+	fn build(&self, app: &mut App) {
+		app.add_systems(
+			Update,
+			(
+				(trackball_view_cube_spawn, trackball_view_cube_input)
+					.chain()
+					.before(TrackballSystemSet::Controller),
+				trackball_view_cube_sync.after(TrackballSystemSet::Camera),
+			),
+		);
+	}
+}
+
+/// Orientation cube of [`TrackballCamera`] rendered in a corner of its viewport, see
+/// [`TrackballViewCubePlugin`].
+///
+/// Added to an entity with a [`TrackballController`] and a [`TrackballCamera`] component, it
+/// spawns an overlay camera in the top right corner of the camera viewport rendering a cube which
+/// rotates along with the camera and hence with the constellation of its controller. The cube
+/// is aligned with the world axes where its faces are colored red, green, and blue for the x, y,
+/// and z-axis, respectively, with lighter colors on the positive side.
+///
+/// Clicking a face, an edge, or a corner of the cube orbits the camera to look at the clicked
+/// piece from outside of the cube with [`TrackballCamera::view_up`] pointing up on screen, if
+/// possible, whereas dragging the cube orbits the camera by mouse. While the cursor hovers the
+/// cube, the hovered piece is highlighted and [`TrackballViewport`] is stolen.
+///
+/// ```no_run
+/// use bevy::prelude::*;
+/// use bevy_trackball::prelude::*;
+///
+/// fn setup(mut commands: Commands) {
+/// 	let [target, eye, up] = [Vec3::ZERO, Vec3::Z * 10.0, Vec3::Y];
+/// 	commands.spawn((
+/// 		TrackballController::default(),
+/// 		TrackballCamera::look_at(target, eye, up),
+/// 		TrackballViewCube::default(),
+/// 		Camera3d::default(),
+/// 	));
+/// }
+/// ```
+///
+/// [`TrackballController`]: crate::TrackballController
+#[derive(Component, Debug, Clone)]
+pub struct TrackballViewCube {
+	/// Size of the overlay viewport in logical pixels. Default is `120.0`.
+	pub size: f32,
+	/// Margin of the overlay viewport to the top right corner in logical pixels. Default is `8.0`.
+	pub margin: f32,
+	/// Render layer of the overlay camera and the cube. Default is `31`.
+	///
+	/// If already used by another view cube, the next lower free layer above the default layer `0`
+	/// is used instead, so multiple view cubes neither overlap nor share their hover highlights.
+	pub layer: usize,
+	overlay: Option<Entity>,
+	drag: Option<Drag>,
+}
+
+impl Default for TrackballViewCube {
+	fn default() -> Self {
+		Self {
+			size: 120.0,
+			margin: 8.0,
+			layer: 31,
+			overlay: None,
+			drag: None,
+		}
+	}
+}
+
+/// Mouse drag on [`TrackballViewCube`].
+#[derive(Debug, Clone)]
+struct Drag {
+	/// Cursor position where the drag started.
+	pos: Vec2,
+	/// Whether the cursor has moved further than a click.
+	moved: bool,
+	/// Orbit operation.
+	orbit: Orbit<f32>,
+}
+
+/// Overlay camera of [`TrackballViewCube`].
+#[derive(Component)]
+struct Overlay {
+	cube: Entity,
+	layer: usize,
+	pieces: Vec<Entity>,
+	hover: Handle<StandardMaterial>,
+}
+
+/// Face, edge, or corner piece of [`TrackballViewCube`] pointing in direction `dir`.
+#[derive(Component)]
+struct Piece {
+	dir: IVec3,
+	color: Handle<StandardMaterial>,
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn trackball_view_cube_spawn(
+	mut commands: Commands,
+	mut meshes: ResMut<Assets<Mesh>>,
+	mut materials: ResMut<Assets<StandardMaterial>>,
+	mut cubes: Query<(Entity, &Camera, &RenderTarget, &mut TrackballViewCube)>,
+	overlays: Query<(Entity, &Overlay)>,
+) {
+	let mut layers = Vec::new();
+	for (overlay_id, overlay) in &overlays {
+		if cubes
+			.get(overlay.cube)
+			.map_or(true, |(_cube_id, _camera, _target, cube)| {
+				cube.overlay != Some(overlay_id)
+			}) {
+			for &piece in &overlay.pieces {
+				commands.entity(piece).despawn();
+			}
+			commands.entity(overlay_id).despawn();
+		} else {
+			layers.push(overlay.layer);
+		}
+	}
+	for (cube_id, camera, target, mut cube) in &mut cubes {
+		if cube.overlay.is_some() {
+			continue;
+		}
+		let layer = (1..=cube.layer)
+			.rev()
+			.find(|layer| !layers.contains(layer))
+			.unwrap_or(cube.layer);
+		layers.push(layer);
+		let render_layer = RenderLayers::layer(layer);
+		let unlit = |color: Color| StandardMaterial {
+			base_color: color,
+			unlit: true,
+			..default()
+		};
+		let hover = materials.add(unlit(Color::srgb(1.0, 0.8, 0.0)));
+		let mut pieces = Vec::new();
+		for x in -1..=1 {
+			for y in -1..=1 {
+				for z in -1..=1 {
+					let dir = IVec3::new(x, y, z);
+					if dir == IVec3::ZERO {
+						continue;
+					}
+					let color = match dir.abs().element_sum() {
+						1 => {
+							let axis = dir.abs().as_vec3() * 0.6;
+							let base = Vec3::splat(if dir.element_sum() > 0 { 0.4 } else { 0.1 });
+							Color::srgb_from_array((base + axis).to_array())
+						}
+						_ => Color::srgb(0.7, 0.7, 0.7),
+					};
+					let color = materials.add(unlit(color));
+					let min = dir.as_vec3().map(|c| if c > 0.0 { INNER } else { -1.0 });
+					let max = dir.as_vec3().map(|c| if c < 0.0 { -INNER } else { 1.0 });
+					let piece = commands
+						.spawn((
+							Piece {
+								dir,
+								color: color.clone(),
+							},
+							Mesh3d(meshes.add(Cuboid::from_size(max - min - GAP))),
+							MeshMaterial3d(color),
+							Transform::from_translation((min + max) * 0.5),
+							render_layer.clone(),
+						))
+						.id();
+					pieces.push(piece);
+				}
+			}
+		}
+		let overlay = commands
+			.spawn((
+				Overlay {
+					cube: cube_id,
+					layer,
+					pieces,
+					hover,
+				},
+				Camera3d::default(),
+				Camera {
+					order: camera.order + 1,
+					clear_color: ClearColorConfig::None,
+					is_active: false,
+					..default()
+				},
+				target.clone(),
+				Projection::from(OrthographicProjection {
+					scaling_mode: ScalingMode::Fixed {
+						width: 3.6,
+						height: 3.6,
+					},
+					..OrthographicProjection::default_3d()
+				}),
+				render_layer,
+			))
+			.id();
+		cube.overlay = Some(overlay);
+	}
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+#[allow(clippy::too_many_arguments)]
+fn trackball_view_cube_input(
+	mut viewport: ResMut<TrackballViewport>,
+	mouse_input: Res<ButtonInput<MouseButton>>,
+	primary_windows: Query<Entity, With<PrimaryWindow>>,
+	windows: Query<&Window>,
+	mut cubes: Query<(
+		Entity,
+		&RenderTarget,
+		&TrackballCamera,
+		&mut TrackballViewCube,
+	)>,
+	overlays: Query<(&Camera, &GlobalTransform, &Overlay)>,
+	mut pieces: Query<(&Piece, &mut MeshMaterial3d<StandardMaterial>)>,
+	mut trackball_events: MessageWriter<TrackballMessage>,
+	mut stealing: Local<bool>,
+) {
+	let mut steal = false;
+	for (group, target, trackball, mut cube) in &mut cubes {
+		let Some((camera, transform, overlay)) =
+			cube.overlay.and_then(|overlay| overlays.get(overlay).ok())
+		else {
+			continue;
+		};
+		let window = match target {
+			RenderTarget::Window(WindowRef::Primary) => primary_windows.single().ok(),
+			RenderTarget::Window(WindowRef::Entity(window)) => Some(*window),
+			_ => None,
+		}
+		.and_then(|window| windows.get(window).ok());
+		let (Some(window), Some(rect)) = (window, camera.logical_viewport_rect()) else {
+			continue;
+		};
+		let cursor = window.cursor_position().filter(|&pos| rect.contains(pos));
+		let hover = cursor
+			.and_then(|pos| camera.viewport_to_world(transform, pos).ok())
+			.and_then(|ray| {
+				let aabb = Aabb3d::new(Vec3::ZERO, Vec3::ONE);
+				let cast = RayCast3d::from_ray(ray, DISTANCE * 2.0);
+				cast.aabb_intersection_at(&aabb)
+					.map(|distance| ray.get_point(distance))
+			})
+			.map(|pos| {
+				pos.map(|c| {
+					if c > INNER {
+						1.0
+					} else if c < -INNER {
+						-1.0
+					} else {
+						0.0
+					}
+				})
+			})
+			.map(|pos| pos.as_ivec3());
+		let mut iter = pieces.iter_many_mut(&overlay.pieces);
+		while let Some((piece, mut material)) = iter.fetch_next() {
+			let color = if hover == Some(piece.dir) && cube.drag.is_none() {
+				&overlay.hover
+			} else {
+				&piece.color
+			};
+			if material.0 != *color {
+				material.0 = color.clone();
+			}
+		}
+		if let Some(pos) = cursor
+			&& hover.is_some()
+			&& mouse_input.just_pressed(MouseButton::Left)
+		{
+			let mut orbit = Orbit::<f32>::default();
+			orbit.compute(&Point2::from(pos - rect.min), &Point2::from(rect.size()));
+			cube.drag = Some(Drag {
+				pos,
+				moved: false,
+				orbit,
+			});
+		}
+		if let Some(drag) = &mut cube.drag {
+			if let Some(pos) = window.cursor_position() {
+				drag.moved |= pos.distance(drag.pos) > CLICK;
+				if drag.moved
+					&& let Some(rot) = drag
+						.orbit
+						.compute(&Point2::from(pos - rect.min), &Point2::from(rect.size()))
+				{
+					trackball_events.write(TrackballMessage::orbit(group, rot, Point3::origin()));
+				}
+			}
+			if !mouse_input.pressed(MouseButton::Left) {
+				if !drag.moved
+					&& let Some(dir) = hover
+					&& let Some(frame) = align(trackball, dir)
+				{
+					let rot = trackball.frame.view().rotation.inverse() * frame.view().rotation;
+					trackball_events.write(TrackballMessage::orbit(group, rot, Point3::origin()));
+				}
+				cube.drag = None;
+			}
+		}
+		let idle = mouse_input
+			.get_pressed()
+			.all(|&button| mouse_input.just_pressed(button));
+		steal |= cube.drag.is_some() || hover.is_some() && idle;
+	}
+	if steal {
+		viewport.set_stolen(Some(1));
+	} else if *stealing {
+		viewport.set_stolen(None);
+	}
+	*stealing = steal;
+}
+
+#[allow(clippy::needless_pass_by_value, clippy::type_complexity)]
+fn trackball_view_cube_sync(
+	cubes: Query<(&Camera, &Transform, &TrackballViewCube), Without<Overlay>>,
+	mut overlays: Query<(&mut Camera, &mut Transform), With<Overlay>>,
+) {
+	for (camera, transform, cube) in &cubes {
+		let Some((mut overlay, mut overlay_transform)) = cube
+			.overlay
+			.and_then(|overlay| overlays.get_mut(overlay).ok())
+		else {
+			continue;
+		};
+		let rotation = transform.rotation;
+		let translation = rotation * Vec3::Z * DISTANCE;
+		if overlay_transform.rotation != rotation || overlay_transform.translation != translation {
+			overlay_transform.rotation = rotation;
+			overlay_transform.translation = translation;
+		}
+		let rect = camera
+			.physical_viewport_rect()
+			.zip(camera.target_scaling_factor())
+			.and_then(|(rect, scale)| {
+				#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+				let (size, margin) = (
+					(cube.size * scale).round() as u32,
+					(cube.margin * scale).round() as u32,
+				);
+				let fits = rect.width() >= size + 2 * margin && rect.height() >= size + 2 * margin;
+				fits.then(|| {
+					let pos = UVec2::new(rect.max.x - size - margin, rect.min.y + margin);
+					(pos, UVec2::splat(size))
+				})
+			});
+		let old_rect = (overlay.viewport.as_ref())
+			.map(|viewport| (viewport.physical_position, viewport.physical_size));
+		let is_active = camera.is_active && rect.is_some();
+		if old_rect != rect || overlay.is_active != is_active {
+			overlay.viewport = rect.map(|(physical_position, physical_size)| Viewport {
+				physical_position,
+				physical_size,
+				..default()
+			});
+			overlay.is_active = is_active;
+		}
+	}
+}
+
+/// Frame of `trackball` with eye in direction `dir` of cube piece.
+///
+/// Prefers [`TrackballCamera::view_up`] pointing up on screen, otherwise
+/// [`TrackballCamera::view_forward`] as for [`StandardView::Top`] and [`StandardView::Bottom`],
+/// otherwise the current yaw axis.
+///
+/// [`StandardView::Top`]: crate::StandardView::Top
+/// [`StandardView::Bottom`]: crate::StandardView::Bottom
+fn align(trackball: &TrackballCamera, dir: IVec3) -> Option<Frame<f32>> {
+	let frame = &trackball.frame;
+	let dir = Dir3::new(dir.as_vec3()).ok()?;
+	let sign = dir.dot(*trackball.view_up).signum();
+	let up = [
+		*trackball.view_up,
+		*trackball.view_forward * sign,
+		Vec3::from(frame.yaw_axis().into_inner()),
+	]
+	.into_iter()
+	.find_map(|up| Dir3::new(up.reject_from_normalized(*dir)).ok())?;
+	let target = *frame.target();
+	let eye = target + Vector3::from(*dir) * frame.distance();
+	Some(Frame::look_at(target, &eye, &Vector3::from(*up)))
+}